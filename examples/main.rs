@@ -4,7 +4,6 @@ use fst::{
 };
 use regex_automata::DenseDFA;
 use rustdoc_seeker::RustDoc;
-use std::fs;
 
 const DOC_JSON_PATHS: [&str; 3] = [
     "doc-json/core.json",
@@ -13,19 +12,7 @@ const DOC_JSON_PATHS: [&str; 3] = [
 ];
 
 fn main() {
-    let rustdoc = DOC_JSON_PATHS
-        .into_iter()
-        .map(|path| {
-            fs::read_to_string(path)
-                .expect(&format!("Failed to read file {}", path))
-                .parse()
-                .expect(&format!("Failed to parse file {path}"))
-        })
-        .reduce(|mut all_docs: RustDoc, current_doc| {
-            all_docs.extend(current_doc);
-            all_docs
-        })
-        .expect("At least one rustdoc file must be provided");
+    let rustdoc = RustDoc::from_paths(DOC_JSON_PATHS).expect("Failed to read rustdoc files");
     let seeker = rustdoc.build();
 
     let dfa = DenseDFA::new(".*dedup.*").unwrap();
@@ -38,6 +25,10 @@ fn main() {
         println!("Edit Distance {}", i);
     }
 
+    for i in seeker.fuzzy("dedXp") {
+        println!("Fuzzy {}", i);
+    }
+
     let subsq = Subsequence::new("dedup");
     for i in seeker.search(&subsq) {
         println!("Subsequence {}", i);
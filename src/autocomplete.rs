@@ -0,0 +1,145 @@
+use crate::seeker::{edit_distances, DocItem, RustDocSeeker};
+
+/// Autocompleter performs fuzzy prefix searching for a query typed incrementally,
+/// e.g. from a search box that queries on every keystroke.
+///
+/// An item matches when some prefix of its name is within `max_distance` edits of the query,
+/// which is what `fst::automaton::Levenshtein::new(query, max_distance).starts_with()` matches.
+///
+/// Instead of building a new automaton for every query, only the first query is looked up in the
+/// index with a `Levenshtein` automaton, and the matched names of it and every longer prefix of
+/// the last query are cached. Since a name matching a query also matches all prefixes of the
+/// query, a query extending the last one only needs to check the names matched by the last one.
+///
+/// When the query shrinks, the cached results for the remaining prefix are reused directly.
+/// When earlier characters of the query are changed, results cached for the prefixes after the
+/// first changed character are dropped, and recomputed from the longest common prefix of the
+/// new and the last query. If the query shrinks or changes before the end of the first query,
+/// there is nothing to reuse, and the new query is looked up in the index again.
+///
+/// # Example
+///
+/// ```
+/// # use rustdoc_seeker::{Autocompleter, RustDoc};
+/// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+/// # let seeker = rustdoc.clone().build();
+/// let mut autocompleter = Autocompleter::new(&seeker, 1);
+/// autocompleter.update("ded");
+/// autocompleter.update("dedu");
+/// assert_eq!(
+///     autocompleter
+///         .update("dedXp")
+///         .into_iter()
+///         .map(|item| format!("{}", item))
+///         .collect::<Vec<_>>(),
+///     vec![
+///         "alloc/vec/struct.Vec.html#method.dedup",
+///         "alloc/vec/struct.Vec.html#method.dedup_by",
+///         "alloc/vec/struct.Vec.html#method.dedup_by_key",
+///     ],
+/// );
+///
+/// // Looked up in the index again, every name is within 1 edit of "d".
+/// assert_eq!(autocompleter.update("d").len(), seeker.iter().count());
+///
+/// // Names are matched in full even if keys of the index are truncated.
+/// let truncated = rustdoc.builder().max_key_len(4).build();
+/// let mut autocompleter = Autocompleter::new(&truncated, 0);
+/// assert_eq!(
+///     autocompleter
+///         .update("dedup_b")
+///         .into_iter()
+///         .map(|item| item.name())
+///         .collect::<Vec<_>>(),
+///     vec!["dedup_by", "dedup_by_key"],
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug)]
+pub struct Autocompleter<'a, D: AsRef<[u8]> = Vec<u8>> {
+    seeker: &'a RustDocSeeker<D>,
+    max_distance: u32,
+    query: Vec<char>,
+    /// Length of the query looked up in the index, in chars.
+    seed_len: usize,
+    /// `cache[i]` contains the index values of names matching `query[..seed_len + i]`.
+    cache: Vec<Vec<u64>>,
+}
+
+impl<'a, D: AsRef<[u8]>> Autocompleter<'a, D> {
+    /// Create an Autocompleter on `seeker`, which may have its FST in memory or memory mapped,
+    /// see `RustDocSeeker::load_parts`.
+    pub fn new(seeker: &'a RustDocSeeker<D>, max_distance: u32) -> Autocompleter<'a, D> {
+        Autocompleter {
+            seeker,
+            max_distance,
+            query: Vec::new(),
+            seed_len: 0,
+            cache: Vec::new(),
+        }
+    }
+
+    /// Update the query and return the matched DocItems.
+    pub fn update(&mut self, query: &str) -> Vec<&'a DocItem> {
+        let chars = query.chars().collect::<Vec<_>>();
+        let common = self
+            .query
+            .iter()
+            .zip(&chars)
+            .take_while(|(a, b)| a == b)
+            .count();
+        if common < self.seed_len {
+            self.cache.clear();
+        } else {
+            self.cache.truncate(common - self.seed_len + 1);
+        }
+        if self.cache.is_empty() {
+            self.seed_len = chars.len();
+            self.cache.push(self.seeker.fuzzy_prefix_groups(query, self.max_distance));
+        }
+
+        for len in self.seed_len + self.cache.len()..=chars.len() {
+            let prefix = &chars[..len];
+            let seeker = self.seeker;
+            let max_distance = self.max_distance as usize;
+            let matched = self.cache[len - self.seed_len - 1]
+                .iter()
+                .copied()
+                .filter(|&idx| matched_items(seeker, idx, prefix, max_distance).next().is_some())
+                .collect();
+            self.cache.push(matched);
+        }
+        self.query = chars;
+
+        let seeker = self.seeker;
+        let max_distance = self.max_distance as usize;
+        self.cache[self.query.len() - self.seed_len]
+            .iter()
+            .flat_map(|&idx| matched_items(seeker, idx, &self.query, max_distance))
+            .collect()
+    }
+}
+
+/// Items of the group `idx` whose names match `query`. A group has several names if keys are
+/// truncated by `RustDocSeekerBuilder::max_key_len`, and items of the same name are adjacent.
+fn matched_items<'a, 'q, D: AsRef<[u8]>>(
+    seeker: &'a RustDocSeeker<D>,
+    idx: u64,
+    query: &'q [char],
+    max_distance: usize,
+) -> impl Iterator<Item=&'a DocItem> + 'q
+where
+    'a: 'q,
+{
+    seeker
+        .group(idx)
+        .chunk_by(|a, b| a.name() == b.name())
+        .filter(move |items| {
+            edit_distances(query, items[0].name())
+                .into_iter()
+                .min()
+                .unwrap_or_default()
+                <= max_distance
+        })
+        .flatten()
+}
@@ -16,10 +16,40 @@
 //! );
 //! # Ok::<(), Box<dyn std::error::Error>>(())
 //! ```
+//!
+//! # Features
+//!
+//! - `serde` (default): `Serialize` and `Deserialize` for `DocItem` and related types, saving and
+//!   loading indexes, and `RustDocSeeker::to_json`. It only gates this API, `serde` and
+//!   `serde_json` are still compiled without it to parse rustdoc JSON.
+//! - `rayon`: find the groups of items sharing a key in parallel when building an index, before
+//!   inserting them into the FST in order. Measured with `doc-json/std.json` (7099 items) on one
+//!   CPU, the median time of `RustDoc::build` is 3.4 ms without it and 4.2 ms with it, as the
+//!   pass is a small part of the build. It may only pay off with many cores and larger indexes.
 
+mod autocomplete;
+mod markdown;
 mod parser;
+mod render;
 mod rustdoc_types;
 mod seeker;
 
+pub use autocomplete::Autocompleter;
 pub use parser::RustDocParseError;
-pub use seeker::{DocItem, DocItemKind, RustDoc, RustDocSeeker, TypeItem};
+pub use seeker::{
+    BuildError,
+    DocItem,
+    DocItemKind,
+    ImplBlock,
+    IndexStats,
+    LinkType,
+    MatchTier,
+    MethodOrigin,
+    ParseDocItemKindError,
+    RustDoc,
+    RustDocSeeker,
+    RustDocSeekerBuilder,
+    SourceSpan,
+    Stability,
+    TypeItem,
+};
@@ -0,0 +1,219 @@
+/// Info strings of the fenced code blocks in the markdown, e.g. `rust,no_run` for
+/// ```` ```rust,no_run ````.
+pub(crate) fn code_block_infos(markdown: &str) -> Vec<&str> {
+    let mut infos = Vec::new();
+    // The fence of the code block we are in
+    let mut fence: Option<&str> = None;
+    for line in markdown.lines() {
+        let Some((marker, rest)) = parse_fence(line) else {
+            continue;
+        };
+        match fence {
+            None => {
+                infos.push(rest.trim());
+                fence = Some(marker);
+            },
+            // A closing fence is at least as long as the opening one, with nothing after it
+            Some(open) if marker.starts_with(open) && rest.trim().is_empty() => fence = None,
+            Some(_) => (),
+        }
+    }
+    infos
+}
+
+/// Split a code fence line like ```` ```rust ```` into the fence and the rest of the line.
+fn parse_fence(line: &str) -> Option<(&str, &str)> {
+    let trimmed = line.trim_start_matches(' ');
+    if line.len() - trimmed.len() > 3 {
+        return None;
+    }
+    let fence_len = |c: char| trimmed.len() - trimmed.trim_start_matches(c).len();
+    let len = fence_len('`').max(fence_len('~'));
+    (len >= 3).then(|| trimmed.split_at(len))
+}
+
+/// Strip the markdown into plain text: code fences, lines hidden from Rust code blocks,
+/// definitions of reference links, backticks of inline code and the syntax of links are removed,
+/// while the text of links and the content of code is kept.
+pub(crate) fn strip_markdown(markdown: &str) -> String {
+    let mut lines = Vec::new();
+    // The fence of the code block we are in, and whether it is Rust code
+    let mut fence: Option<(&str, bool)> = None;
+    for line in markdown.lines() {
+        match (fence, parse_fence(line)) {
+            (None, Some((marker, rest))) => fence = Some((marker, is_rust_code_block(rest.trim()))),
+            (Some((open, _)), Some((marker, rest)))
+                if marker.starts_with(open) && rest.trim().is_empty() =>
+            {
+                fence = None
+            },
+            (Some((_, true)), _) if is_hidden_line(line) => (),
+            (Some(_), _) => lines.push(line.to_string()),
+            (None, None) if is_link_definition(line) => (),
+            (None, None) => lines.push(strip_inline(line)),
+        }
+    }
+    lines.join("\n").trim_end().to_string()
+}
+
+/// Whether the line of a Rust code block is hidden by rustdoc, like `# fn main() {}`.
+fn is_hidden_line(line: &str) -> bool {
+    let trimmed = line.trim_start();
+    trimmed == "#" || trimmed.starts_with("# ")
+}
+
+/// Whether the line defines a reference link like `[Vec]: crate::vec::Vec`.
+fn is_link_definition(line: &str) -> bool {
+    line.trim_start()
+        .strip_prefix('[')
+        .and_then(|rest| rest.split_once("]:"))
+        .is_some_and(|(label, _)| !label.is_empty() && !label.contains(']'))
+}
+
+/// Remove backticks of inline code and the syntax of links from a line, keeping the text.
+fn strip_inline(line: &str) -> String {
+    let mut text = String::with_capacity(line.len());
+    let mut rest = line;
+    while let Some(c) = rest.chars().next() {
+        match c {
+            '`' => {
+                let ticks = rest.len() - rest.trim_start_matches('`').len();
+                let (fence, after) = rest.split_at(ticks);
+                match after.find(fence) {
+                    Some(end) => {
+                        text.push_str(&after[..end]);
+                        rest = &after[end + ticks..];
+                    },
+                    None => {
+                        text.push_str(fence);
+                        rest = after;
+                    },
+                }
+            },
+            '[' => match closing_bracket(rest, '[', ']') {
+                Some(end) => {
+                    text.push_str(&strip_inline(&rest[1..end]));
+                    rest = &rest[end + 1..];
+                    // Skip the destination of `[text](url)` or the label of `[text][label]`
+                    let skip = match rest.chars().next() {
+                        Some('(') => closing_bracket(rest, '(', ')'),
+                        Some('[') => closing_bracket(rest, '[', ']'),
+                        _ => None,
+                    };
+                    if let Some(end) = skip {
+                        rest = &rest[end + 1..];
+                    }
+                },
+                None => {
+                    text.push('[');
+                    rest = &rest[1..];
+                },
+            },
+            _ => {
+                text.push(c);
+                rest = &rest[c.len_utf8()..];
+            },
+        }
+    }
+    text
+}
+
+/// The byte position of the bracket closing the one `s` starts with, skipping nested brackets.
+fn closing_bracket(s: &str, open: char, close: char) -> Option<usize> {
+    let mut depth = 0;
+    for (i, c) in s.char_indices() {
+        if c == open {
+            depth += 1;
+        } else if c == close {
+            depth -= 1;
+            if depth == 0 {
+                return Some(i);
+            }
+        }
+    }
+    None
+}
+
+/// Whether a code block with the info string is tested as Rust code by rustdoc.
+pub(crate) fn is_rust_code_block(info: &str) -> bool {
+    info.split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|token| !token.is_empty())
+        .all(|token| {
+            matches!(
+                token,
+                "rust"
+                    | "ignore"
+                    | "should_panic"
+                    | "no_run"
+                    | "compile_fail"
+                    | "test_harness"
+                    | "standalone_crate"
+            ) || token.starts_with("ignore-")
+                || token.starts_with("edition")
+                || token
+                    .strip_prefix('E')
+                    .is_some_and(|code| code.bytes().all(|b| b.is_ascii_digit()))
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_code_block_infos() {
+        let markdown = "\
+Example
+
+```
+let a = 1;
+```
+
+````text
+```
+not a fence
+```
+````
+
+~~~rust,no_run
+loop {}
+~~~";
+        let infos = code_block_infos(markdown);
+        assert_eq!(infos, vec!["", "text", "rust,no_run"]);
+        assert_eq!(
+            infos
+                .into_iter()
+                .filter(|info| is_rust_code_block(info))
+                .count(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_strip_markdown() {
+        let markdown = "\
+Returns a [`Vec`] of [items](crate::Item) like `[T]`, see [this][link].
+
+```
+# fn main() {
+let v = vec![1];
+# }
+```
+
+```text
+# not hidden
+```
+
+[`Vec`]: crate::vec::Vec
+[link]: https://example.com";
+        assert_eq!(
+            strip_markdown(markdown),
+            "\
+Returns a Vec of items like [T], see this.
+
+let v = vec![1];
+
+# not hidden"
+        );
+    }
+}
@@ -1,6 +1,8 @@
 use crate::{
+    render::Renderer,
     rustdoc_types::{
         Crate as RustDocRoot,
+        Impl,
         Import,
         Item as RustDocItem,
         ItemEnum as RustDocItemEnum,
@@ -11,14 +13,17 @@ use crate::{
         Visibility,
         FORMAT_VERSION,
     },
-    seeker::{DocItem, LinkType, RustDoc, TypeItem},
+    seeker::{DocItem, FnTypes, LinkType, MethodOrigin, RustDoc, SourceSpan, TypeItem},
     DocItemKind,
 };
 use rustc_hash::FxHashMap;
 use std::{
-    cell::{OnceCell, RefCell},
+    cell::{Cell, OnceCell, RefCell},
     collections::BTreeSet,
+    fs,
+    io,
     iter,
+    path::Path,
     str::FromStr,
 };
 use string_cache::DefaultAtom as Atom;
@@ -32,21 +37,150 @@ pub enum RustDocParseError {
     /// Failed to parse the input string as a rustdoc JSON document.
     #[error("invalid input JSON string")]
     Json(#[from] serde_json::Error),
-    /// The rustdoc JSON format has an unsupported version.
+    /// Failed to read the rustdoc JSON document.
+    #[error("failed to read rustdoc JSON file")]
+    Io(#[from] io::Error),
+    /// The rustdoc JSON format has an unsupported version, which is the `format_version` of the
+    /// document. See `RustDoc::parse_with_versions` to accept other versions.
     #[error("unsupported rustdoc format version: {0}")]
     UnsupportedFormatVersion(u32),
 }
 
+impl RustDoc {
+    /// Parse multiple rustdoc JSON documents and merge them into one RustDoc,
+    /// without reading them from the file system.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// # use std::fs;
+    /// let alloc = fs::read_to_string("doc-json/alloc.json")?;
+    /// let proc_macro = fs::read_to_string("doc-json/proc_macro.json")?;
+    /// let rustdoc = RustDoc::from_contents(&[&alloc, &proc_macro])?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn from_contents(contents: &[&str]) -> Result<RustDoc, RustDocParseError> {
+        let mut rustdoc = RustDoc::new(BTreeSet::new());
+        for content in contents {
+            rustdoc = rustdoc.merge(content.parse()?);
+        }
+        Ok(rustdoc)
+    }
+
+    /// Read multiple rustdoc JSON files and merge them into one RustDoc,
+    /// e.g. `doc-json/core.json`, `doc-json/alloc.json` and `doc-json/std.json` downloaded by
+    /// `download-rust-docs-json.sh`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// let rustdoc = RustDoc::from_paths(["doc-json/alloc.json", "doc-json/proc_macro.json"])?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn from_paths<P: AsRef<Path>>(
+        paths: impl IntoIterator<Item=P>,
+    ) -> Result<RustDoc, RustDocParseError> {
+        let mut rustdoc = RustDoc::new(BTreeSet::new());
+        for path in paths {
+            rustdoc = rustdoc.merge(fs::read_to_string(path)?.parse()?);
+        }
+        Ok(rustdoc)
+    }
+}
+
 impl FromStr for RustDoc {
     type Err = RustDocParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let doc: RustDocRoot = serde_json::from_str(s)?;
-        if doc.format_version != FORMAT_VERSION {
+        RustDoc::from_root(serde_json::from_str(s)?, &[FORMAT_VERSION], false)
+    }
+}
+
+impl RustDoc {
+    /// Parse a rustdoc JSON document from a reader, without reading the whole document into a
+    /// string first.
+    ///
+    /// Reading from a file directly is slow, wrap it with `std::io::BufReader`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// # use std::{fs::File, io::BufReader};
+    /// let file = File::open("doc-json/alloc.json")?;
+    /// let rustdoc = RustDoc::from_reader(BufReader::new(file))?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn from_reader<R: io::Read>(reader: R) -> Result<RustDoc, RustDocParseError> {
+        RustDoc::from_root(serde_json::from_reader(reader)?, &[FORMAT_VERSION], false)
+    }
+
+    /// Parse a rustdoc JSON document from bytes, e.g. read from an archive, without validating
+    /// them as a UTF-8 string first. The same as `FromStr` otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// let bytes = std::fs::read("doc-json/alloc.json")?;
+    /// let rustdoc = RustDoc::from_slice(&bytes)?;
+    /// assert!(!rustdoc.is_empty());
+    /// assert!(RustDoc::from_slice(b"\xff").is_err());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn from_slice(bytes: &[u8]) -> Result<RustDoc, RustDocParseError> {
+        RustDoc::from_root(serde_json::from_slice(bytes)?, &[FORMAT_VERSION], false)
+    }
+
+    /// Parse a rustdoc JSON document already parsed as a `serde_json::Value`, e.g. after
+    /// filtering it, without serializing it into a string again. The same as `FromStr` otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// let content = std::fs::read_to_string("doc-json/alloc.json")?;
+    /// let value: serde_json::Value = serde_json::from_str(&content)?;
+    /// let rustdoc = RustDoc::from_value(value)?;
+    /// assert_eq!(rustdoc.len(), content.parse::<RustDoc>()?.len());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn from_value(value: serde_json::Value) -> Result<RustDoc, RustDocParseError> {
+        RustDoc::from_root(serde_json::from_value(value)?, &[FORMAT_VERSION], false)
+    }
+
+    /// Parse a rustdoc JSON document of any of the `accepted` format versions, instead of only
+    /// the version supported by this crate, which is the only one accepted by `FromStr`.
+    ///
+    /// Documents of other versions are parsed as the supported version, so only accept versions
+    /// verified to be compatible. Parsing may still fail with `RustDocParseError::Json` if the
+    /// format is incompatible.
+    pub fn parse_with_versions(s: &str, accepted: &[u32]) -> Result<RustDoc, RustDocParseError> {
+        RustDoc::from_root(serde_json::from_str(s)?, accepted, false)
+    }
+
+    /// Parse a rustdoc JSON document including items with restricted visibility like
+    /// `pub(super)`, which are skipped by `FromStr`, e.g. to search private helpers of a crate.
+    /// Use `DocItem::is_public` to tell them apart.
+    ///
+    /// Private items are only in documents generated with `--document-private-items`.
+    pub fn parse_including_private(s: &str) -> Result<RustDoc, RustDocParseError> {
+        RustDoc::from_root(serde_json::from_str(s)?, &[FORMAT_VERSION], true)
+    }
+
+    fn from_root(
+        doc: RustDocRoot,
+        accepted: &[u32],
+        include_private: bool,
+    ) -> Result<RustDoc, RustDocParseError> {
+        if !accepted.contains(&doc.format_version) {
             return Err(RustDocParseError::UnsupportedFormatVersion(
                 doc.format_version,
             ));
         }
+        let format_version = doc.format_version;
 
         #[derive(Debug, Clone, Default)]
         enum ItemTypeParent {
@@ -67,6 +201,13 @@ impl FromStr for RustDoc {
                 associate_item: Atom,
             },
         }
+        /// Where an associated item is on the page of a type, see `ItemNode::blanket_placements`.
+        #[derive(Debug, Clone)]
+        struct Placement {
+            type_parent: Atom,
+            owning_impl: Option<Atom>,
+            disambiguator: usize,
+        }
         #[derive(Debug, Clone)]
         struct ItemNode {
             item: RustDocItem,
@@ -74,6 +215,11 @@ impl FromStr for RustDoc {
             kind: RefCell<DocItemKind>,
             parent: OnceCell<ItemTypeParent>,
             imported_by: RefCell<Vec<Atom>>,
+            disambiguator: Cell<usize>,
+            owning_impl: OnceCell<Atom>,
+            /// Types other than the parent the item is on the pages of, because it is defined in
+            /// a blanket impl applying to all of them.
+            blanket_placements: RefCell<Vec<Placement>>,
         }
 
         impl From<&'_ ItemNode> for TypeItem {
@@ -85,23 +231,34 @@ impl FromStr for RustDoc {
             }
         }
 
+        let renderer = Renderer {
+            paths: &doc.paths,
+        };
         let nodes = doc
             .index
             .into_iter()
             .map(|(id, item)| {
                 (Atom::from(id.0), ItemNode {
-                    name: item
-                        .name
-                        .as_deref()
+                    name: parse_doc_keyword(&item.attrs)
+                        .or(item.name.as_deref())
                         .or(match &item.inner {
                             RustDocItemEnum::Import(import) => Some(import.name.as_str()),
+                            // Trait impls are named after the trait
+                            RustDocItemEnum::Impl(Impl {
+                                trait_: Some(trait_),
+                                ..
+                            }) => Some(renderer.path_name(trait_)),
                             _ => None,
                         })
+                        .map(|name| name.strip_prefix("r#").unwrap_or(name))
                         .unwrap_or_default()
                         .into(),
                     kind: RefCell::new(map_doc_item_kind(&item)),
                     parent: OnceCell::new(),
                     imported_by: RefCell::new(Vec::new()),
+                    disambiguator: Cell::new(0),
+                    owning_impl: OnceCell::new(),
+                    blanket_placements: RefCell::new(Vec::new()),
                     item,
                 })
             })
@@ -140,23 +297,21 @@ impl FromStr for RustDoc {
 
             // Adjust parents for direct descendants
             match &node.item.inner {
+                // prelude modules usually contain non-inline items which do not have an actual
+                // page
                 R::Module(Module {
                     items, ..
-                }) => {
-                    // prelude modules usually contain non-inline items which do not have an actual
-                    // page
-                    if &*node.name != "prelude" {
-                        items
-                            .iter()
-                            .filter_map(|item| nodes.get(&Atom::from(&*item.0)))
-                            .for_each(|item| {
-                                item.parent
-                                    .set(ItemTypeParent::ModuleItem {
-                                        path_parent: id.clone(),
-                                    })
-                                    .ok();
-                            });
-                    }
+                }) if &*node.name != "prelude" => {
+                    items
+                        .iter()
+                        .filter_map(|item| nodes.get(&Atom::from(&*item.0)))
+                        .for_each(|item| {
+                            item.parent
+                                .set(ItemTypeParent::ModuleItem {
+                                    path_parent: id.clone(),
+                                })
+                                .ok();
+                        });
                 },
                 R::Union(Union {
                     fields: items, ..
@@ -258,7 +413,7 @@ impl FromStr for RustDoc {
                 impls, ..
             }) = &node.item.inner
             {
-                impls
+                let mut impls = impls
                     .iter()
                     .filter_map(|item| nodes.get(&Atom::from(&*item.0)))
                     .inspect(|item| {
@@ -271,35 +426,96 @@ impl FromStr for RustDoc {
                     })
                     .filter_map(|item| {
                         if let R::Impl(imp) = &item.item.inner {
-                            Some(imp)
+                            Some((Atom::from(&*item.item.id.0), imp))
                         } else {
                             None
                         }
                     })
-                    .flat_map(|imp| &imp.items)
-                    .filter_map(|item| nodes.get(&Atom::from(&*item.0)))
-                    .for_each(|item| {
-                        item.parent
+                    .collect::<Vec<_>>();
+                // Items are disambiguated in the order they are rendered on the page
+                impls.sort_by_key(|(_, imp)| impl_render_order(imp));
+                let mut anchors = FxHashMap::<String, usize>::default();
+                impls
+                    .into_iter()
+                    .flat_map(|(impl_id, imp)| {
+                        let blanket = imp.blanket_impl.is_some();
+                        imp.items.iter().map(move |item| (impl_id.clone(), blanket, item))
+                    })
+                    .filter_map(|(impl_id, blanket, item)| {
+                        Some((impl_id, blanket, nodes.get(&Atom::from(&*item.0))?))
+                    })
+                    .for_each(|(impl_id, blanket, item)| {
+                        let is_child = item
+                            .parent
                             .set(ItemTypeParent::AssociateItem {
                                 type_parent: id.clone(),
                             })
-                            .ok();
+                            .is_ok();
                         fix_associated_item_kind(&mut item.kind.borrow_mut(), &item.item);
+                        if !is_child && !blanket {
+                            return;
+                        }
+                        let count = anchors.entry(TypeItem::from(item).to_string()).or_default();
+                        if is_child {
+                            item.owning_impl.set(impl_id).ok();
+                            item.disambiguator.set(*count);
+                        } else {
+                            // Items of a blanket impl are shared by all types it applies to
+                            item.blanket_placements.borrow_mut().push(Placement {
+                                type_parent: id.clone(),
+                                owning_impl: Some(impl_id),
+                                disambiguator: *count,
+                            });
+                        }
+                        *count += 1;
                     });
             }
         }
 
         // Cache paths for Module and glob Import nodes
+        struct ParseContext<'a> {
+            crate_name: Atom,
+            renderer: Renderer<'a>,
+            include_private: bool,
+        }
+
+        impl ParseContext<'_> {
+            /// Identify the definition of a node among all crates.
+            fn def_id(&self, node: &ItemNode) -> Atom {
+                Atom::from(format!("{}:{}", self.crate_name, node.item.id.0))
+            }
+        }
+
+        let context = ParseContext {
+            crate_name: nodes
+                .get(&Atom::from(&*doc.root.0))
+                .map(|root| root.name.clone())
+                .unwrap_or_default(),
+            renderer,
+            include_private,
+        };
         let mut path_cache = FxHashMap::<Atom, Vec<Atom>>::default();
         let mut items = BTreeSet::new();
         nodes
             .values()
-            .filter(|node| !matches!(node.item.visibility, Visibility::Restricted { .. }))
+            .filter(|node| {
+                include_private
+                    || !matches!(node.item.visibility, Visibility::Restricted { .. })
+            })
             .filter(|node| {
                 // For Import nodes, let the importees to generate duplicates for each Import.
                 !matches!(node.item.inner, RustDocItemEnum::Import(_))
             })
-            .filter(|node| !matches!(node.item.inner, RustDocItemEnum::Impl(_)))
+            // Inherent impls are not searchable by name
+            .filter(|node| {
+                !matches!(
+                    node.item.inner,
+                    RustDocItemEnum::Impl(Impl {
+                        trait_: None,
+                        ..
+                    })
+                )
+            })
             .filter_map(|node| {
                 let parent = node.parent.get()?;
                 if let ItemTypeParent::AssociateItem {
@@ -327,12 +543,15 @@ impl FromStr for RustDoc {
                     omit_self: bool,
                     nodes: &FxHashMap<Atom, ItemNode>,
                     path_cache: &mut FxHashMap<Atom, Vec<Atom>>,
+                    include_private: bool,
                 ) -> Vec<Atom> {
                     let cache_key = Atom::from(&*starting_node.item.id.0);
                     if let Some(paths) = path_cache.get(&cache_key).filter(|_| !omit_self) {
                         return paths.clone();
                     }
-                    if matches!(starting_node.item.visibility, Visibility::Restricted { .. }) {
+                    if !include_private
+                        && matches!(starting_node.item.visibility, Visibility::Restricted { .. })
+                    {
                         path_cache.insert(starting_node.name.clone(), vec![]);
                         return vec![];
                     }
@@ -359,7 +578,9 @@ impl FromStr for RustDoc {
                             let parent_paths = nodes
                                 .get(path_parent)
                                 .into_iter()
-                                .flat_map(|parent| generate_path(parent, false, nodes, path_cache));
+                                .flat_map(|parent| {
+                                    generate_path(parent, false, nodes, path_cache, include_private)
+                                });
                             paths.extend(
                                 parent_paths.map(|p| p.to_string() + &tail).map(Into::into),
                             );
@@ -375,7 +596,13 @@ impl FromStr for RustDoc {
                         let Some(import_node) = nodes.get(import_node) else {
                             continue;
                         };
-                        paths.extend(generate_path(import_node, omit_self, nodes, path_cache));
+                        paths.extend(generate_path(
+                            import_node,
+                            omit_self,
+                            nodes,
+                            path_cache,
+                            include_private,
+                        ));
                     }
                     if !omit_self {
                         path_cache.insert(cache_key, paths.clone());
@@ -383,57 +610,208 @@ impl FromStr for RustDoc {
                     paths.clone()
                 }
 
+                /// The path of the module `node` is defined in, following parent modules only
+                /// instead of imports, i.e. the path of `node` which is not a re-export.
+                fn definition_path(
+                    node: &ItemNode,
+                    nodes: &FxHashMap<Atom, ItemNode>,
+                ) -> Option<Atom> {
+                    match node.parent.get()? {
+                        ItemTypeParent::ModuleItem {
+                            path_parent,
+                        } => {
+                            let parent = nodes.get(path_parent)?;
+                            let parent_path = definition_path(parent, nodes)?;
+                            if parent_path.is_empty() {
+                                Some(parent.name.clone())
+                            } else {
+                                Some(Atom::from(format!("{}::{}", parent_path, parent.name)))
+                            }
+                        },
+                        ItemTypeParent::Root => Some(Atom::from("")),
+                        _ => None,
+                    }
+                }
+
                 fn append_associate_items(
                     nodes: &FxHashMap<Atom, ItemNode>,
                     node: &ItemNode,
-                    type_parent: &Atom,
+                    placement: &Placement,
                     gen_link_type: &mut impl FnMut(TypeItem) -> LinkType,
                     items: &mut BTreeSet<DocItem>,
                     path_cache: &mut FxHashMap<Atom, Vec<Atom>>,
+                    context: &ParseContext,
                 ) {
-                    let Some(type_parent) = nodes.get(type_parent) else {
+                    let Some(type_parent) = nodes.get(&placement.type_parent) else {
                         return;
                     };
                     let name = TypeItem::from(node);
                     let desc = Atom::from(node.item.docs.as_deref().unwrap_or_default());
+                    let must_use = parse_must_use(&node.item.attrs);
+                    let (unstable_feature, unstable_issue) =
+                        parse_unstable(&node.item.attrs).unzip();
+                    let unstable_issue = unstable_issue.flatten();
+                    let stable_since = parse_stable_since(&node.item.attrs);
+                    let negative_impl = is_negative_impl(&node.item);
+                    let deprecation = node.item.deprecation.as_ref();
+                    let deprecation_note =
+                        deprecation.and_then(|d| d.note.as_deref()).map(Atom::from);
+                    let required_assoc_const = is_required_assoc_const(&node.item);
+                    let const_value = parse_const_value(&node.item);
+                    let public = is_public(&node.item);
+                    let hidden =
+                        is_doc_hidden(&node.item.attrs) || is_doc_hidden(&type_parent.item.attrs);
+                    let source_location = parse_span(&node.item);
+                    let generics = parse_generics(&node.item, &context.renderer);
+                    let signature = parse_signature(&node.item, &context.renderer);
+                    let fn_types =
+                        parse_fn_types(&node.item, &context.renderer, Some(&type_parent.name));
+                    let assoc_type_bounds = match (&type_parent.item.inner, &node.item.inner) {
+                        (
+                            RustDocItemEnum::Trait(_),
+                            RustDocItemEnum::AssocType {
+                                bounds, ..
+                            },
+                        ) if !bounds.is_empty() => {
+                            Some(Atom::from(context.renderer.bounds(bounds)))
+                        },
+                        _ => None,
+                    };
+                    let owning_impl = placement.owning_impl.as_ref().and_then(|id| nodes.get(id));
+                    let owning_impl_id = owning_impl.map(|imp| context.def_id(imp));
+                    let (blanket_impl, mut auto_trait_impl) =
+                        match owning_impl.map(|imp| &imp.item.inner) {
+                            Some(RustDocItemEnum::Impl(imp)) => {
+                                (imp.blanket_impl.is_some(), imp.synthetic)
+                            },
+                            _ => (false, false),
+                        };
+                    // Auto trait impls have no items, only the impl itself is listed
+                    if let RustDocItemEnum::Impl(imp) = &node.item.inner {
+                        auto_trait_impl |= imp.synthetic;
+                    }
+                    let defining_trait = owning_impl.and_then(|imp| match &imp.item.inner {
+                        RustDocItemEnum::Impl(Impl {
+                            trait_: Some(trait_),
+                            ..
+                        }) => Some(Atom::from(context.renderer.path_name(trait_))),
+                        _ => None,
+                    });
+                    let method_origin = match (&type_parent.item.inner, &node.item.inner) {
+                        (RustDocItemEnum::Trait(_), RustDocItemEnum::Function(func)) => {
+                            Some(if func.has_body {
+                                MethodOrigin::TraitProvided
+                            } else {
+                                MethodOrigin::TraitRequired
+                            })
+                        },
+                        (_, RustDocItemEnum::Function(_)) if blanket_impl => {
+                            Some(MethodOrigin::BlanketImpl)
+                        },
+                        (_, RustDocItemEnum::Function(_)) if defining_trait.is_some() => {
+                            Some(MethodOrigin::TraitImpl)
+                        },
+                        (_, RustDocItemEnum::Function(_)) if owning_impl.is_some() => {
+                            Some(MethodOrigin::Inherent)
+                        },
+                        _ => None,
+                    };
                     let type_parent_typeitem = TypeItem::from(type_parent);
                     let parent_reexports = type_parent.imported_by.borrow();
-                    let new_items = parent_reexports
+                    let def_id = context.def_id(node);
+                    let paths = parent_reexports
                         .iter()
                         .filter_map(|imported_by| nodes.get(imported_by))
                         .chain(iter::once(type_parent))
                         .flat_map(|parent| {
-                            generate_path(parent, true, nodes, path_cache)
+                            generate_path(parent, true, nodes, path_cache, context.include_private)
                                 .into_iter()
                                 .map(move |path| (parent, path))
                         })
-                        .map(|(type_parent, path)| DocItem {
-                            name: name.clone(),
-                            link_type: gen_link_type(TypeItem {
-                                kind: type_parent_typeitem.kind,
-                                name: type_parent.name.clone(),
-                            }),
-                            desc: desc.clone(),
-                            path,
-                        });
+                        .collect::<Vec<_>>();
+                    let path_count = paths.len();
+                    let def_path = definition_path(type_parent, nodes);
+                    let new_items = paths.into_iter().map(|(parent, path)| DocItem {
+                        name: name.clone(),
+                        link_type: gen_link_type(TypeItem {
+                            kind: type_parent_typeitem.kind,
+                            name: parent.name.clone(),
+                        }),
+                        desc: desc.clone(),
+                        must_use: must_use.clone(),
+                        unstable_feature: unstable_feature.clone(),
+                        unstable_issue: unstable_issue.clone(),
+                        stable_since: stable_since.clone(),
+                        negative_impl,
+                        deprecated: deprecation.is_some(),
+                        deprecation_note: deprecation_note.clone(),
+                        required_assoc_const,
+                        const_value: const_value.clone(),
+                        public,
+                        fn_types: fn_types.clone(),
+                        generics: generics.clone(),
+                        signature: signature.clone(),
+                        hidden,
+                        source_location: source_location.clone(),
+                        assoc_type_bounds: assoc_type_bounds.clone(),
+                        owning_impl: owning_impl_id.clone(),
+                        blanket_impl,
+                        auto_trait_impl,
+                        defining_trait: defining_trait.clone(),
+                        method_origin,
+                        reexport: def_path.as_ref() != Some(&path),
+                        disambiguator: placement.disambiguator,
+                        def_id: def_id.clone(),
+                        path_count,
+                        path,
+                    });
                     items.extend(new_items);
                 }
 
+                let placement = |type_parent: &Atom| Placement {
+                    type_parent: type_parent.clone(),
+                    owning_impl: node.owning_impl.get().cloned(),
+                    disambiguator: node.disambiguator.get(),
+                };
                 match parent {
                     ItemTypeParent::AssociateItem {
                         type_parent,
-                    } => {
+                    } if matches!(node.item.inner, RustDocItemEnum::Impl(_)) => {
+                        let RustDocItemEnum::Impl(imp) = &node.item.inner else {
+                            unreachable!()
+                        };
+                        let fragment = Atom::from(context.renderer.impl_fragment(imp));
                         append_associate_items(
                             &nodes,
                             node,
-                            type_parent,
-                            &mut |typeitem| LinkType::AssociateItem {
+                            &placement(type_parent),
+                            &mut |typeitem| LinkType::Impl {
                                 page_item: typeitem,
+                                fragment: fragment.clone(),
                             },
                             &mut items,
                             &mut path_cache,
+                            &context,
                         );
                     },
+                    ItemTypeParent::AssociateItem {
+                        type_parent,
+                    } => {
+                        let placements = node.blanket_placements.borrow();
+                        for placement in iter::once(&placement(type_parent)).chain(&*placements) {
+                            append_associate_items(
+                                &nodes,
+                                node,
+                                placement,
+                                &mut |typeitem| LinkType::AssociateItem {
+                                    page_item: typeitem,
+                                },
+                                &mut items,
+                                &mut path_cache,
+                                &context,
+                            );
+                        }
+                    },
                     ItemTypeParent::SubAssociateItem {
                         type_parent,
                         associate_item,
@@ -446,36 +824,87 @@ impl FromStr for RustDoc {
                         append_associate_items(
                             &nodes,
                             node,
-                            type_parent,
+                            &placement(type_parent),
                             &mut |typeitem| LinkType::SubAssociateItem {
                                 page_item: typeitem,
                                 parent: parent_associate_item.clone(),
                             },
                             &mut items,
                             &mut path_cache,
+                            &context,
                         );
                     },
                     _ => {
                         let name = TypeItem::from(node);
                         let desc = Atom::from(node.item.docs.as_deref().unwrap_or_default());
-                        let new_items = generate_path(node, true, &nodes, &mut path_cache)
-                            .into_iter()
-                            .map(|path| DocItem {
-                                name: name.clone(),
-                                link_type: if name.kind == DocItemKind::Module {
-                                    LinkType::Index
-                                } else {
-                                    LinkType::Page
-                                },
-                                desc: desc.clone(),
-                                path,
-                            });
+                        let must_use = parse_must_use(&node.item.attrs);
+                        let (unstable_feature, unstable_issue) =
+                            parse_unstable(&node.item.attrs).unzip();
+                        let unstable_issue = unstable_issue.flatten();
+                        let stable_since = parse_stable_since(&node.item.attrs);
+                        let negative_impl = is_negative_impl(&node.item);
+                        let deprecation = node.item.deprecation.as_ref();
+                        let deprecation_note =
+                            deprecation.and_then(|d| d.note.as_deref()).map(Atom::from);
+                        let required_assoc_const = is_required_assoc_const(&node.item);
+                        let const_value = parse_const_value(&node.item);
+                        let public = is_public(&node.item);
+                        let hidden = is_doc_hidden(&node.item.attrs);
+                        let source_location = parse_span(&node.item);
+                        let generics = parse_generics(&node.item, &context.renderer);
+                        let signature = parse_signature(&node.item, &context.renderer);
+                        let fn_types = parse_fn_types(&node.item, &context.renderer, None);
+                        let def_id = context.def_id(node);
+                        let paths = generate_path(
+                            node,
+                            true,
+                            &nodes,
+                            &mut path_cache,
+                            context.include_private,
+                        );
+                        let path_count = paths.len();
+                        let def_path = definition_path(node, &nodes);
+                        let new_items = paths.into_iter().map(|path| DocItem {
+                            name: name.clone(),
+                            link_type: if name.kind == DocItemKind::Module {
+                                LinkType::Index
+                            } else {
+                                LinkType::Page
+                            },
+                            desc: desc.clone(),
+                            must_use: must_use.clone(),
+                            unstable_feature: unstable_feature.clone(),
+                            unstable_issue: unstable_issue.clone(),
+                            stable_since: stable_since.clone(),
+                            negative_impl,
+                            deprecated: deprecation.is_some(),
+                            deprecation_note: deprecation_note.clone(),
+                            required_assoc_const,
+                            const_value: const_value.clone(),
+                            public,
+                            fn_types: fn_types.clone(),
+                            generics: generics.clone(),
+                            signature: signature.clone(),
+                            hidden,
+                            source_location: source_location.clone(),
+                            assoc_type_bounds: None,
+                            owning_impl: None,
+                            blanket_impl: false,
+                            auto_trait_impl: false,
+                            defining_trait: None,
+                            method_origin: None,
+                            reexport: def_path.as_ref() != Some(&path),
+                            disambiguator: 0,
+                            def_id: def_id.clone(),
+                            path_count,
+                            path,
+                        });
                         items.extend(new_items);
                     },
                 };
             });
 
-        Ok(RustDoc::new(items))
+        Ok(RustDoc::with_format_version(items, format_version))
     }
 }
 
@@ -485,6 +914,7 @@ fn map_doc_item_kind(item: &RustDocItem) -> DocItemKind {
         seeker::DocItemKind as K,
     };
     match &item.inner {
+        R::Module(_) if parse_doc_keyword(&item.attrs).is_some() => K::Keyword,
         R::Module(_) => K::Module,
         R::ExternCrate {
             ..
@@ -500,7 +930,7 @@ fn map_doc_item_kind(item: &RustDocItem) -> DocItemKind {
         R::TraitAlias(_) => K::TraitAlias,
         R::Impl(_) => K::Impl,
         R::TypeAlias(_) => K::Typedef,
-        R::OpaqueTy(_) => unimplemented!("don't know how to handle OpaqueTy"),
+        R::OpaqueTy(_) => K::Existential,
         R::Constant(_) => K::Constant,
         R::Static(_) => K::Static,
         R::ForeignType => K::ForeignType,
@@ -520,6 +950,138 @@ fn map_doc_item_kind(item: &RustDocItem) -> DocItemKind {
     }
 }
 
+/// Impl blocks are rendered in the order of inherent impls, trait impls, auto trait impls and
+/// blanket impls.
+fn impl_render_order(imp: &Impl) -> u8 {
+    match imp {
+        Impl {
+            trait_: None, ..
+        } => 0,
+        Impl {
+            synthetic: true, ..
+        } => 2,
+        Impl {
+            blanket_impl: Some(_),
+            ..
+        } => 3,
+        _ => 1,
+    }
+}
+
+/// Whether the item is an impl block like `impl !Send for Foo`.
+fn is_negative_impl(item: &RustDocItem) -> bool {
+    matches!(
+        item.inner,
+        RustDocItemEnum::Impl(Impl {
+            negative: true,
+            ..
+        })
+    )
+}
+
+/// Whether the item is an associated const without a value like `const N: usize;`.
+/// `None` if the item is not an associated const.
+fn is_required_assoc_const(item: &RustDocItem) -> Option<bool> {
+    if let RustDocItemEnum::AssocConst {
+        default, ..
+    } = &item.inner
+    {
+        Some(default.is_none())
+    } else {
+        None
+    }
+}
+
+/// The value of a constant or an associated constant, preferring the evaluated value over the
+/// expression in the source. `None` if the value is not rendered by rustdoc, which writes `_`.
+fn parse_const_value(item: &RustDocItem) -> Option<Atom> {
+    let value = match &item.inner {
+        RustDocItemEnum::Constant(constant) => constant.value.as_ref().unwrap_or(&constant.expr),
+        RustDocItemEnum::AssocConst {
+            default, ..
+        } => default.as_ref()?,
+        _ => return None,
+    };
+    (value != "_").then(|| Atom::from(value.as_str()))
+}
+
+/// Generic parameters of an item like `<T, A: Allocator>`, see `Renderer::generics`.
+fn parse_generics(item: &RustDocItem, renderer: &Renderer) -> Option<Atom> {
+    use crate::rustdoc_types::{ItemEnum as R, *};
+
+    let generics = match &item.inner {
+        R::Union(Union {
+            generics, ..
+        })
+        | R::Struct(Struct {
+            generics, ..
+        })
+        | R::Enum(Enum {
+            generics, ..
+        })
+        | R::Function(Function {
+            generics, ..
+        })
+        | R::Trait(Trait {
+            generics, ..
+        })
+        | R::TraitAlias(TraitAlias {
+            generics, ..
+        })
+        | R::TypeAlias(TypeAlias {
+            generics, ..
+        })
+        | R::AssocType {
+            generics, ..
+        } => generics,
+        _ => return None,
+    };
+    let generics = renderer.generics(&generics.params);
+    (!generics.is_empty()).then(|| Atom::from(generics))
+}
+
+/// The signature of a function like `fn dedup(&mut self)`, see `Renderer::signature`.
+fn parse_signature(item: &RustDocItem, renderer: &Renderer) -> Option<Atom> {
+    let (RustDocItemEnum::Function(func), Some(name)) = (&item.inner, &item.name) else {
+        return None;
+    };
+    Some(Atom::from(renderer.signature(name, func)))
+}
+
+/// Names of the types in the signature of a function, with `Self` replaced by `self_name`.
+fn parse_fn_types(
+    item: &RustDocItem,
+    renderer: &Renderer,
+    self_name: Option<&Atom>,
+) -> Option<FnTypes> {
+    let RustDocItemEnum::Function(func) = &item.inner else {
+        return None;
+    };
+    let type_name = |ty| {
+        renderer.type_name(ty).map(|name| match self_name {
+            Some(self_name) if name == "Self" => self_name.clone(),
+            _ => Atom::from(name),
+        })
+    };
+    Some(FnTypes {
+        inputs: func
+            .decl
+            .inputs
+            .iter()
+            .filter_map(|(_, ty)| type_name(ty))
+            .collect(),
+        output: match &func.decl.output {
+            Some(ty) => type_name(ty),
+            None => Some(Atom::from("()")),
+        },
+    })
+}
+
+/// Whether the item is visible outside its crate, unlike `pub(crate)` and `pub(in path)`.
+fn is_public(item: &RustDocItem) -> bool {
+    !matches!(item.visibility, Visibility::Crate | Visibility::Restricted { .. })
+}
+
 fn fix_associated_item_kind(kind: &mut DocItemKind, item: &RustDocItem) {
     use crate::{rustdoc_types::ItemEnum as R, seeker::DocItemKind as K};
 
@@ -530,6 +1092,133 @@ fn fix_associated_item_kind(kind: &mut DocItemKind, item: &RustDocItem) {
     }
 }
 
+/// Extract the keyword documented by a `#[doc(keyword = "...")]` module.
+fn parse_doc_keyword(attrs: &[String]) -> Option<&str> {
+    attrs.iter().find_map(|attr| {
+        attr.strip_prefix("#[doc(keyword = \"")?
+            .strip_suffix("\")]")
+    })
+}
+
+/// Extract the message of a `#[must_use]` attribute, or an empty string if no message is given.
+fn parse_must_use(attrs: &[String]) -> Option<Atom> {
+    attrs.iter().find_map(|attr| {
+        let rest = attr.strip_prefix("#[must_use")?.trim_start();
+        if rest.starts_with(']') {
+            return Some(Atom::from(""));
+        }
+        let literal = rest.strip_prefix('=')?.trim().strip_suffix(']')?.trim_end();
+        Some(Atom::from(unquote_str_literal(literal)?))
+    })
+}
+
+/// The location of the item in the source code, if the document has spans.
+fn parse_span(item: &RustDocItem) -> Option<SourceSpan> {
+    let span = item.span.as_ref()?;
+    Some(SourceSpan {
+        filename: Atom::from(&*span.filename.to_string_lossy()),
+        begin: span.begin,
+        end: span.end,
+    })
+}
+
+/// Whether the item is marked with `#[doc(hidden)]`.
+fn is_doc_hidden(attrs: &[String]) -> bool {
+    attrs.iter().any(|attr| {
+        parse_attr_args(attr, "doc").is_some_and(|args| args.iter().any(|(k, _)| *k == "hidden"))
+    })
+}
+
+/// Extract the `feature` and `issue` of a `#[unstable(feature = "...", issue = "...")]` attribute.
+/// The issue is `None` if it is `"none"`.
+fn parse_unstable(attrs: &[String]) -> Option<(Atom, Option<Atom>)> {
+    let args = attrs
+        .iter()
+        .find_map(|attr| parse_attr_args(attr, "unstable"))?;
+    let get = |key| {
+        args.iter()
+            .find(|(k, _)| *k == key)
+            .map(|(_, v)| Atom::from(v.as_str()))
+    };
+    Some((
+        get("feature")?,
+        get("issue").filter(|issue| &**issue != "none"),
+    ))
+}
+
+/// Extract the `since` version of a `#[stable(feature = "...", since = "...")]` attribute.
+fn parse_stable_since(attrs: &[String]) -> Option<Atom> {
+    attrs
+        .iter()
+        .find_map(|attr| parse_attr_args(attr, "stable"))?
+        .into_iter()
+        .find(|(key, _)| *key == "since")
+        .map(|(_, since)| Atom::from(since))
+}
+
+/// Parse the arguments of an attribute like `#[name(key = "value", flag)]`,
+/// where the value of a flag is empty.
+fn parse_attr_args<'a>(attr: &'a str, name: &str) -> Option<Vec<(&'a str, String)>> {
+    let mut rest = attr
+        .strip_prefix("#[")?
+        .strip_prefix(name)?
+        .trim_start()
+        .strip_prefix('(')?
+        .strip_suffix(")]")?;
+    let mut args = Vec::new();
+    loop {
+        rest = rest.trim_start_matches(|c: char| c == ',' || c.is_whitespace());
+        if rest.is_empty() {
+            return Some(args);
+        }
+        let key_end = rest
+            .find(|c: char| c == '=' || c == ',' || c.is_whitespace())
+            .unwrap_or(rest.len());
+        let key = &rest[..key_end];
+        rest = rest[key_end..].trim_start();
+        let Some(value) = rest.strip_prefix('=') else {
+            args.push((key, String::new()));
+            continue;
+        };
+        let value = value.trim_start();
+        // Find the closing quote of the string literal
+        let mut escaped = false;
+        let (len, _) = value.char_indices().skip(1).find(|&(_, c)| {
+            let found = c == '"' && !escaped;
+            escaped = c == '\\' && !escaped;
+            found
+        })?;
+        args.push((key, unquote_str_literal(&value[..=len])?));
+        rest = &value[len + 1..];
+    }
+}
+
+/// Unescape a pretty-printed Rust string literal such as `"foo \"bar\""`.
+fn unquote_str_literal(literal: &str) -> Option<String> {
+    let literal = literal.strip_prefix('"')?.strip_suffix('"')?;
+    let mut result = String::with_capacity(literal.len());
+    let mut chars = literal.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+        match chars.next()? {
+            'n' => result.push('\n'),
+            'r' => result.push('\r'),
+            't' => result.push('\t'),
+            '0' => result.push('\0'),
+            // Line continuation, skip the leading whitespace of the next line
+            '\n' => {
+                let rest = chars.as_str().trim_start();
+                chars = rest.chars();
+            },
+            c => result.push(c),
+        }
+    }
+    Some(result)
+}
+
 impl DocItemKind {
     pub fn is_associated_item(&self) -> bool {
         matches!(
@@ -545,8 +1234,361 @@ impl DocItemKind {
 #[cfg(test)]
 mod test {
     use super::*;
+    use serde_json::{json, Value};
     use std::fs;
 
+    #[test]
+    fn test_parse_must_use() {
+        assert_eq!(parse_must_use(&["#[inline]".into()]), None);
+        assert_eq!(parse_must_use(&["#[must_use]".into()]).as_deref(), Some(""));
+        assert_eq!(
+            parse_must_use(&[
+                "#[must_use =\n\"this returns the result of the operation, \\\n                      \
+                 without modifying the original\"]"
+                    .into()
+            ])
+            .as_deref(),
+            Some("this returns the result of the operation, without modifying the original")
+        );
+    }
+
+    #[test]
+    fn test_parse_unstable() {
+        assert_eq!(
+            parse_unstable(&[
+                "#[unstable(feature = \"strict_overflow_ops\", issue = \"118260\")]".into()
+            ]),
+            Some((
+                Atom::from("strict_overflow_ops"),
+                Some(Atom::from("118260"))
+            ))
+        );
+        assert_eq!(
+            parse_unstable(&[
+                "#[unstable(reason = \"not \\\"public\\\"\", issue = \"none\", feature =\n\"fd\")]"
+                    .into()
+            ]),
+            Some((Atom::from("fd"), None))
+        );
+        assert_eq!(
+            parse_unstable(&["#[stable(feature = \"rust1\", since = \"1.0.0\")]".into()]),
+            None
+        );
+    }
+
+    /// Build a rustdoc JSON item of the fixture crate.
+    fn fixture_item(id: &str, name: &str, attrs: &[&str], inner: Value) -> (String, Value) {
+        let item = json!({
+            "id": id,
+            "crate_id": 0,
+            "name": name,
+            "span": null,
+            "visibility": "public",
+            "docs": null,
+            "links": {},
+            "attrs": attrs,
+            "deprecation": null,
+            "inner": inner,
+        });
+        (id.to_string(), item)
+    }
+
+    fn fixture_module(id: &str, name: &str, attrs: &[&str], items: &[&str]) -> (String, Value) {
+        let inner =
+            json!({"module": {"is_crate": id == "0:0", "items": items, "is_stripped": false}});
+        fixture_item(id, name, attrs, inner)
+    }
+
+    /// Build a rustdoc JSON document of a crate named `fixture` with the root module `0:0`.
+    fn fixture_json(items: impl IntoIterator<Item=(String, Value)>, format_version: u32) -> String {
+        json!({
+            "root": "0:0",
+            "crate_version": null,
+            "includes_private": true,
+            "index": items.into_iter().collect::<serde_json::Map<_, _>>(),
+            "paths": {},
+            "external_crates": {},
+            "format_version": format_version,
+        })
+        .to_string()
+    }
+
+    /// Parse a crate named `fixture` with the root module `0:0`.
+    fn parse_fixture(items: impl IntoIterator<Item=(String, Value)>) -> RustDoc {
+        fixture_json(items, FORMAT_VERSION).parse().unwrap()
+    }
+
+    #[test]
+    fn test_include_private() {
+        let mut private_mod = fixture_module("0:1", "private_mod", &[], &["0:2"]);
+        private_mod.1["visibility"] = json!({"restricted": {"parent": "0:0", "path": "super"}});
+        let json = fixture_json(
+            [
+                fixture_module("0:0", "fixture", &[], &["0:1"]),
+                private_mod,
+                fixture_module("0:2", "inner", &[], &[]),
+            ],
+            FORMAT_VERSION,
+        );
+
+        let rustdoc: RustDoc = json.parse().unwrap();
+        assert_eq!(
+            rustdoc.iter().map(|item| item.to_string()).collect::<Vec<_>>(),
+            ["fixture/index.html"]
+        );
+
+        let rustdoc = RustDoc::parse_including_private(&json).unwrap();
+        assert_eq!(
+            rustdoc
+                .iter()
+                .map(|item| (item.to_string(), item.is_public()))
+                .collect::<Vec<_>>(),
+            [
+                ("fixture/index.html".to_string(), true),
+                ("fixture/private_mod/inner/index.html".to_string(), true),
+                ("fixture/private_mod/index.html".to_string(), false),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_doc_hidden() {
+        let unit_struct = json!({"struct": {
+            "kind": "unit",
+            "generics": {"params": [], "where_predicates": []},
+            "impls": [],
+        }});
+        let fixture = || {
+            parse_fixture([
+                fixture_module("0:0", "fixture", &[], &["0:1", "0:2"]),
+                fixture_item("0:1", "Hidden", &["#[doc(hidden)]"], unit_struct.clone()),
+                fixture_item("0:2", "Visible", &[], unit_struct.clone()),
+            ])
+        };
+        let rustdoc = fixture();
+        let hidden = rustdoc.iter().find(|item| item.name() == "Hidden").unwrap();
+        assert!(hidden.is_hidden());
+
+        let aut = fst::automaton::Str::new("Hidden");
+        let seeker = rustdoc.build();
+        assert_eq!(seeker.search(&aut).count(), 0);
+        assert_eq!(seeker.search(&fst::automaton::Str::new("Visible")).count(), 1);
+
+        let seeker = fixture().builder().include_hidden(true).build();
+        assert_eq!(seeker.search(&aut).count(), 1);
+    }
+
+    #[test]
+    fn test_format_versions() {
+        let json = fixture_json(
+            [fixture_module("0:0", "fixture", &[], &[])],
+            FORMAT_VERSION + 1,
+        );
+        assert!(matches!(
+            json.parse::<RustDoc>(),
+            Err(RustDocParseError::UnsupportedFormatVersion(version))
+                if version == FORMAT_VERSION + 1
+        ));
+        let rustdoc =
+            RustDoc::parse_with_versions(&json, &[FORMAT_VERSION, FORMAT_VERSION + 1]).unwrap();
+        assert_eq!(rustdoc.iter().count(), 1);
+        assert_eq!(rustdoc.format_versions(), &BTreeSet::from([FORMAT_VERSION + 1]));
+
+        let rustdoc = rustdoc.merge(parse_fixture([fixture_module("0:0", "fixture", &[], &[])]));
+        assert_eq!(
+            rustdoc.format_versions(),
+            &BTreeSet::from([FORMAT_VERSION, FORMAT_VERSION + 1])
+        );
+    }
+
+    #[test]
+    fn test_keyword() {
+        let rustdoc = parse_fixture([
+            fixture_module("0:0", "fixture", &[], &["0:1"]),
+            fixture_module("0:1", "match_keyword", &["#[doc(keyword = \"match\")]"], &[
+            ]),
+        ]);
+        let keyword = rustdoc.iter().find(|item| item.name() == "match").unwrap();
+        assert_eq!(keyword.kind(), DocItemKind::Keyword);
+        assert_eq!(keyword.to_string(), "fixture/keyword.match.html");
+
+        let seeker = rustdoc.builder().separate_keywords(true).build();
+        let aut = fst::automaton::Str::new("match");
+        assert_eq!(seeker.search(&aut).count(), 0);
+        assert_eq!(
+            seeker
+                .search_keywords("ma")
+                .map(|item| item.to_string())
+                .collect::<Vec<_>>(),
+            vec!["fixture/keyword.match.html"]
+        );
+    }
+
+    #[test]
+    fn test_std_keywords() {
+        // Keywords are documented on private modules of std, which the snapshot is built without,
+        // so `keyword.fn` can only be checked here once it is regenerated with them.
+        let data = fs::read_to_string("doc-json/std.json").unwrap();
+        let rustdoc: RustDoc = data.parse().unwrap();
+        let keywords = rustdoc
+            .iter()
+            .filter(|item| item.kind() == DocItemKind::Keyword)
+            .map(|item| item.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(keywords, Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_raw_identifier() {
+        let rustdoc = parse_fixture([
+            fixture_module("0:0", "fixture", &[], &["0:1"]),
+            fixture_module("0:1", "r#try", &[], &["0:2"]),
+            fixture_item(
+                "0:2",
+                "r#match",
+                &[],
+                json!({"macro": "macro_rules! r#match {}"}),
+            ),
+        ]);
+        assert_eq!(
+            rustdoc
+                .iter()
+                .map(|item| item.to_string())
+                .collect::<Vec<_>>(),
+            vec![
+                "fixture/index.html",
+                "fixture/try/macro.match.html",
+                "fixture/try/index.html"
+            ]
+        );
+        assert!(rustdoc.iter().all(|item| !item.name().starts_with("r#")));
+    }
+
+    #[test]
+    fn test_opaque_ty() {
+        let rustdoc = parse_fixture([
+            fixture_module("0:0", "fixture", &[], &["0:1"]),
+            fixture_item(
+                "0:1",
+                "Opaque",
+                &[],
+                json!({"opaque_ty": {
+                    "bounds": [],
+                    "generics": {"params": [], "where_predicates": []},
+                }}),
+            ),
+        ]);
+        let opaque = rustdoc.iter().find(|item| item.name() == "Opaque").unwrap();
+        assert_eq!(opaque.kind(), DocItemKind::Existential);
+        assert_eq!(opaque.to_string(), "fixture/existential.Opaque.html");
+    }
+
+    #[test]
+    fn test_trait_alias() {
+        let rustdoc = parse_fixture([
+            fixture_module("0:0", "fixture", &[], &["0:1"]),
+            fixture_item(
+                "0:1",
+                "Alias",
+                &[],
+                json!({"trait_alias": {
+                    "generics": {"params": [], "where_predicates": []},
+                    "params": [],
+                }}),
+            ),
+        ]);
+        let alias = rustdoc.iter().find(|item| item.name() == "Alias").unwrap();
+        assert_eq!(alias.kind(), DocItemKind::TraitAlias);
+        assert_eq!(alias.to_string(), "fixture/traitalias.Alias.html");
+    }
+
+    #[test]
+    fn test_required_assoc_const() {
+        let rustdoc = parse_fixture([
+            fixture_module("0:0", "fixture", &[], &["0:1"]),
+            fixture_item(
+                "0:1",
+                "Lanes",
+                &[],
+                json!({"trait": {
+                    "is_auto": false,
+                    "is_unsafe": false,
+                    "is_object_safe": true,
+                    "items": ["0:2", "0:3"],
+                    "generics": {"params": [], "where_predicates": []},
+                    "bounds": [],
+                    "implementations": [],
+                }}),
+            ),
+            fixture_item(
+                "0:2",
+                "N",
+                &[],
+                json!({"assoc_const": {"type": {"primitive": "usize"}, "default": null}}),
+            ),
+            fixture_item(
+                "0:3",
+                "M",
+                &[],
+                json!({"assoc_const": {"type": {"primitive": "usize"}, "default": "0"}}),
+            ),
+        ]);
+        let required = |name: &str| {
+            rustdoc
+                .iter()
+                .find(|item| item.name() == name)
+                .unwrap()
+                .is_required_assoc_const()
+        };
+        assert_eq!(required("N"), Some(true));
+        assert_eq!(required("M"), Some(false));
+        assert_eq!(required("Lanes"), None);
+    }
+
+    #[test]
+    fn test_disambiguator() {
+        let data = fs::read_to_string("doc-json/alloc.json").unwrap();
+        let rustdoc: RustDoc = data.parse().unwrap();
+        let urls = rustdoc
+            .iter()
+            .filter(|item| {
+                item.to_string()
+                    .starts_with("alloc/string/struct.String.html#")
+            })
+            .filter(|item| item.name() == "fmt")
+            .map(|item| item.to_string())
+            .collect::<BTreeSet<_>>();
+        assert_eq!(
+            urls,
+            BTreeSet::from([
+                "alloc/string/struct.String.html#method.fmt".to_string(),
+                "alloc/string/struct.String.html#method.fmt-1".to_string(),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_impl_fragment() {
+        let data = fs::read_to_string("doc-json/alloc.json").unwrap();
+        let seeker = data.parse::<RustDoc>().unwrap().build();
+        let aut = fst::automaton::Str::new("Clone");
+        let urls = seeker
+            .search(&aut)
+            .filter(|item| item.kind() == DocItemKind::Impl)
+            .map(|item| item.to_string())
+            .collect::<BTreeSet<_>>();
+        assert!(urls.contains("alloc/vec/struct.Vec.html#impl-Clone-for-Vec%3CT,+A%3E"));
+    }
+
+    #[test]
+    fn test_from_reader() {
+        let file = fs::File::open("doc-json/proc_macro.json").unwrap();
+        let from_reader = RustDoc::from_reader(io::BufReader::new(file)).unwrap();
+        let data = fs::read_to_string("doc-json/proc_macro.json").unwrap();
+        let from_str: RustDoc = data.parse().unwrap();
+        assert!(from_reader.iter().eq(from_str.iter()));
+    }
+
     #[test]
     fn test_parser() {
         let data = fs::read_to_string("doc-json/proc_macro.json").unwrap();
@@ -0,0 +1,482 @@
+use crate::rustdoc_types::{
+    FnDecl,
+    Function,
+    GenericArg,
+    GenericArgs,
+    GenericBound,
+    GenericParamDef,
+    GenericParamDefKind,
+    Id,
+    Impl,
+    ItemSummary,
+    Path,
+    Term,
+    TraitBoundModifier,
+    Type,
+    TypeBinding,
+    TypeBindingKind,
+};
+use itertools::Itertools;
+use rustc_hash::FxHashMap;
+use std::fmt::{self, Write};
+
+/// Renderer writes types and bounds in the Rust syntax,
+/// resolving the names of paths with `Crate::paths`.
+pub(crate) struct Renderer<'a> {
+    pub(crate) paths: &'a FxHashMap<Id, ItemSummary>,
+}
+
+impl Renderer<'_> {
+    /// Render a list of bounds like `Iterator<Item = T> + ?Sized + 'a`.
+    pub(crate) fn bounds(&self, bounds: &[GenericBound]) -> String {
+        let mut result = String::new();
+        self.fmt_bounds(&mut result, bounds).unwrap();
+        result
+    }
+
+    /// Render generic parameters of an item like `<'a, T: Clone, const N: usize>`, without
+    /// defaults and parameters introduced by `impl Trait`. Empty if there is no parameter.
+    pub(crate) fn generics(&self, params: &[GenericParamDef]) -> String {
+        let mut result = String::new();
+        self.fmt_generic_params(&mut result, params).unwrap();
+        result
+    }
+
+    /// Render the signature of a function like `pub fn` items are shown in rustdoc, without
+    /// visibility, ABI and where clauses, e.g. `fn dedup_by_key<F, K>(&mut self, key: F)`.
+    pub(crate) fn signature(&self, name: &str, func: &Function) -> String {
+        let mut result = String::new();
+        self.fmt_signature(&mut result, name, func).unwrap();
+        result
+    }
+
+    /// Render the id rustdoc gives to the section of an impl block, used as the URL fragment,
+    /// like `impl-Clone-for-Vec%3CT,+A%3E` for `impl Clone for Vec<T, A>`.
+    pub(crate) fn impl_fragment(&self, imp: &Impl) -> String {
+        let mut id = String::from("impl-");
+        if let Some(trait_) = &imp.trait_ {
+            self.fmt_path(&mut id, trait_).unwrap();
+            id.push_str("-for-");
+        }
+        self.fmt_type(&mut id, &imp.for_).unwrap();
+        small_url_encode(&id)
+    }
+
+    fn fmt_bounds<W: Write>(&self, f: &mut W, bounds: &[GenericBound]) -> fmt::Result {
+        for (i, bound) in bounds.iter().enumerate() {
+            if i > 0 {
+                f.write_str(" + ")?;
+            }
+            self.fmt_bound(f, bound)?;
+        }
+        Ok(())
+    }
+
+    fn fmt_bound<W: Write>(&self, f: &mut W, bound: &GenericBound) -> fmt::Result {
+        match bound {
+            GenericBound::TraitBound {
+                trait_,
+                generic_params,
+                modifier,
+            } => {
+                match modifier {
+                    TraitBoundModifier::None => (),
+                    TraitBoundModifier::Maybe => f.write_char('?')?,
+                    TraitBoundModifier::MaybeConst => f.write_str("~const ")?,
+                }
+                self.fmt_hrtb(f, generic_params)?;
+                self.fmt_path(f, trait_)
+            },
+            GenericBound::Outlives(lifetime) => f.write_str(lifetime),
+        }
+    }
+
+    /// Render `<'a, T: Clone, const N: usize>`, leaving out parameters of `impl Trait`.
+    fn fmt_generic_params<W: Write>(
+        &self,
+        f: &mut W,
+        generic_params: &[GenericParamDef],
+    ) -> fmt::Result {
+        let mut params = generic_params.iter().filter(|param| {
+            !matches!(param.kind, GenericParamDefKind::Type {
+                synthetic: true,
+                ..
+            })
+        });
+        let Some(first) = params.next() else {
+            return Ok(());
+        };
+        f.write_char('<')?;
+        for (i, param) in std::iter::once(first).chain(params).enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            match &param.kind {
+                GenericParamDefKind::Lifetime {
+                    outlives,
+                } => {
+                    f.write_str(&param.name)?;
+                    if !outlives.is_empty() {
+                        write!(f, ": {}", outlives.join(" + "))?;
+                    }
+                },
+                GenericParamDefKind::Type {
+                    bounds, ..
+                } => {
+                    f.write_str(&param.name)?;
+                    if !bounds.is_empty() {
+                        f.write_str(": ")?;
+                        self.fmt_bounds(f, bounds)?;
+                    }
+                },
+                GenericParamDefKind::Const {
+                    type_, ..
+                } => {
+                    write!(f, "const {}: ", param.name)?;
+                    self.fmt_type(f, type_)?;
+                },
+            }
+        }
+        f.write_char('>')
+    }
+
+    /// Render `for<'a> ` of Higher-Rank Trait Bounds.
+    fn fmt_hrtb<W: Write>(&self, f: &mut W, generic_params: &[GenericParamDef]) -> fmt::Result {
+        let lifetimes = generic_params
+            .iter()
+            .filter(|param| matches!(param.kind, GenericParamDefKind::Lifetime { .. }))
+            .map(|param| &param.name)
+            .join(", ");
+        if !lifetimes.is_empty() {
+            write!(f, "for<{}> ", lifetimes)?;
+        }
+        Ok(())
+    }
+
+    /// The name of a type for searching by signatures, ignoring references and generic arguments,
+    /// like `Vec` for `&mut Vec<T>`. `None` for types without a name like `_`.
+    pub(crate) fn type_name<'p>(&'p self, ty: &'p Type) -> Option<&'p str> {
+        match ty {
+            Type::ResolvedPath(path) => Some(self.path_name(path)),
+            Type::DynTrait(dyn_trait) => dyn_trait
+                .traits
+                .first()
+                .map(|poly_trait| self.path_name(&poly_trait.trait_)),
+            Type::Generic(name) | Type::Primitive(name) => Some(name),
+            Type::FunctionPointer(_) => Some("fn"),
+            Type::Tuple(types) if types.is_empty() => Some("()"),
+            Type::Tuple(_) => Some("tuple"),
+            Type::Slice(_) => Some("slice"),
+            Type::Array {
+                ..
+            } => Some("array"),
+            Type::Pat {
+                type_, ..
+            }
+            | Type::RawPointer {
+                type_, ..
+            }
+            | Type::BorrowedRef {
+                type_, ..
+            } => self.type_name(type_),
+            Type::ImplTrait(bounds) => bounds.iter().find_map(|bound| match bound {
+                GenericBound::TraitBound {
+                    trait_, ..
+                } => Some(self.path_name(trait_)),
+                _ => None,
+            }),
+            Type::QualifiedPath {
+                name, ..
+            } => Some(name),
+            Type::Infer => None,
+        }
+    }
+
+    /// The name of the item referred to by `path`, without generic arguments.
+    pub(crate) fn path_name<'p>(&'p self, path: &'p Path) -> &'p str {
+        self.paths
+            .get(&path.id)
+            .and_then(|summary| summary.path.last())
+            .unwrap_or(&path.name)
+    }
+
+    fn fmt_path<W: Write>(&self, f: &mut W, path: &Path) -> fmt::Result {
+        f.write_str(self.path_name(path))?;
+        if let Some(args) = &path.args {
+            self.fmt_generic_args(f, args)?;
+        }
+        Ok(())
+    }
+
+    fn fmt_generic_args<W: Write>(&self, f: &mut W, args: &GenericArgs) -> fmt::Result {
+        match args {
+            GenericArgs::AngleBracketed {
+                args,
+                bindings,
+            } => {
+                if args.is_empty() && bindings.is_empty() {
+                    return Ok(());
+                }
+                f.write_char('<')?;
+                for (i, arg) in args.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    self.fmt_generic_arg(f, arg)?;
+                }
+                for (i, binding) in bindings.iter().enumerate() {
+                    if i > 0 || !args.is_empty() {
+                        f.write_str(", ")?;
+                    }
+                    self.fmt_binding(f, binding)?;
+                }
+                f.write_char('>')
+            },
+            GenericArgs::Parenthesized {
+                inputs,
+                output,
+            } => {
+                self.fmt_tuple(f, inputs, false)?;
+                if let Some(output) = output {
+                    f.write_str(" -> ")?;
+                    self.fmt_type(f, output)?;
+                }
+                Ok(())
+            },
+        }
+    }
+
+    fn fmt_generic_arg<W: Write>(&self, f: &mut W, arg: &GenericArg) -> fmt::Result {
+        match arg {
+            GenericArg::Lifetime(lifetime) => f.write_str(lifetime),
+            GenericArg::Type(ty) => self.fmt_type(f, ty),
+            GenericArg::Const(constant) => f.write_str(&constant.expr),
+            GenericArg::Infer => f.write_char('_'),
+        }
+    }
+
+    fn fmt_binding<W: Write>(&self, f: &mut W, binding: &TypeBinding) -> fmt::Result {
+        f.write_str(&binding.name)?;
+        self.fmt_generic_args(f, &binding.args)?;
+        match &binding.binding {
+            TypeBindingKind::Equality(Term::Type(ty)) => {
+                f.write_str(" = ")?;
+                self.fmt_type(f, ty)
+            },
+            TypeBindingKind::Equality(Term::Constant(constant)) => {
+                write!(f, " = {}", constant.expr)
+            },
+            TypeBindingKind::Constraint(bounds) => {
+                f.write_str(": ")?;
+                self.fmt_bounds(f, bounds)
+            },
+        }
+    }
+
+    /// Render `(A, B)`, with a trailing comma for a single element tuple type if `is_type`.
+    fn fmt_tuple<W: Write>(&self, f: &mut W, types: &[Type], is_type: bool) -> fmt::Result {
+        f.write_char('(')?;
+        for (i, ty) in types.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            self.fmt_type(f, ty)?;
+        }
+        if is_type && types.len() == 1 {
+            f.write_char(',')?;
+        }
+        f.write_char(')')
+    }
+
+    fn fmt_signature<W: Write>(&self, f: &mut W, name: &str, func: &Function) -> fmt::Result {
+        if func.header.const_ {
+            f.write_str("const ")?;
+        }
+        if func.header.async_ {
+            f.write_str("async ")?;
+        }
+        if func.header.unsafe_ {
+            f.write_str("unsafe ")?;
+        }
+        write!(f, "fn {}", name)?;
+        self.fmt_generic_params(f, &func.generics.params)?;
+        f.write_char('(')?;
+        for (i, (arg, ty)) in func.decl.inputs.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            match ty {
+                // `self`, `&self` and `&'a mut self` are written without the type
+                Type::Generic(name) if arg == "self" && name == "Self" => f.write_str("self")?,
+                Type::BorrowedRef {
+                    lifetime,
+                    mutable,
+                    type_,
+                } if arg == "self" && matches!(&**type_, Type::Generic(name) if name == "Self") => {
+                    f.write_char('&')?;
+                    if let Some(lifetime) = lifetime {
+                        write!(f, "{} ", lifetime)?;
+                    }
+                    if *mutable {
+                        f.write_str("mut ")?;
+                    }
+                    f.write_str("self")?;
+                },
+                _ => {
+                    write!(f, "{}: ", arg)?;
+                    self.fmt_type(f, ty)?;
+                },
+            }
+        }
+        if func.decl.c_variadic {
+            f.write_str(", ...")?;
+        }
+        f.write_char(')')?;
+        if let Some(output) = &func.decl.output {
+            f.write_str(" -> ")?;
+            self.fmt_type(f, output)?;
+        }
+        Ok(())
+    }
+
+    fn fmt_fn_decl<W: Write>(&self, f: &mut W, decl: &FnDecl) -> fmt::Result {
+        f.write_char('(')?;
+        for (i, (_, ty)) in decl.inputs.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            self.fmt_type(f, ty)?;
+        }
+        if decl.c_variadic {
+            f.write_str(", ...")?;
+        }
+        f.write_char(')')?;
+        if let Some(output) = &decl.output {
+            f.write_str(" -> ")?;
+            self.fmt_type(f, output)?;
+        }
+        Ok(())
+    }
+
+    fn fmt_type<W: Write>(&self, f: &mut W, ty: &Type) -> fmt::Result {
+        match ty {
+            Type::ResolvedPath(path) => self.fmt_path(f, path),
+            Type::DynTrait(dyn_trait) => {
+                f.write_str("dyn ")?;
+                for (i, poly_trait) in dyn_trait.traits.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(" + ")?;
+                    }
+                    self.fmt_hrtb(f, &poly_trait.generic_params)?;
+                    self.fmt_path(f, &poly_trait.trait_)?;
+                }
+                if let Some(lifetime) = &dyn_trait.lifetime {
+                    write!(f, " + {}", lifetime)?;
+                }
+                Ok(())
+            },
+            Type::Generic(name) | Type::Primitive(name) => f.write_str(name),
+            Type::FunctionPointer(fn_pointer) => {
+                self.fmt_hrtb(f, &fn_pointer.generic_params)?;
+                if fn_pointer.header.unsafe_ {
+                    f.write_str("unsafe ")?;
+                }
+                f.write_str("fn")?;
+                self.fmt_fn_decl(f, &fn_pointer.decl)
+            },
+            Type::Tuple(types) => self.fmt_tuple(f, types, true),
+            Type::Slice(ty) => {
+                f.write_char('[')?;
+                self.fmt_type(f, ty)?;
+                f.write_char(']')
+            },
+            Type::Array {
+                type_,
+                len,
+            } => {
+                f.write_char('[')?;
+                self.fmt_type(f, type_)?;
+                write!(f, "; {}]", len)
+            },
+            Type::Pat {
+                type_, ..
+            } => self.fmt_type(f, type_),
+            Type::ImplTrait(bounds) => {
+                f.write_str("impl ")?;
+                self.fmt_bounds(f, bounds)
+            },
+            Type::Infer => f.write_char('_'),
+            Type::RawPointer {
+                mutable,
+                type_,
+            } => {
+                f.write_str(if *mutable { "*mut " } else { "*const " })?;
+                self.fmt_type(f, type_)
+            },
+            Type::BorrowedRef {
+                lifetime,
+                mutable,
+                type_,
+            } => {
+                f.write_char('&')?;
+                if let Some(lifetime) = lifetime {
+                    write!(f, "{} ", lifetime)?;
+                }
+                if *mutable {
+                    f.write_str("mut ")?;
+                }
+                self.fmt_type(f, type_)
+            },
+            Type::QualifiedPath {
+                name,
+                args,
+                self_type,
+                trait_,
+            } => {
+                match trait_ {
+                    // rustdoc gives an empty trait path for `Self::Item` in trait definitions
+                    Some(trait_) if !trait_.name.is_empty() => {
+                        f.write_char('<')?;
+                        self.fmt_type(f, self_type)?;
+                        f.write_str(" as ")?;
+                        self.fmt_path(f, trait_)?;
+                        f.write_char('>')?;
+                    },
+                    _ => self.fmt_type(f, self_type)?,
+                }
+                write!(f, "::{}", name)?;
+                self.fmt_generic_args(f, args)
+            },
+        }
+    }
+}
+
+/// Percent-encode an id like rustdoc, keeping chars allowed in URLs and replacing spaces with `+`.
+fn small_url_encode(s: &str) -> String {
+    let mut result = String::with_capacity(s.len());
+    for b in s.bytes() {
+        match b {
+            b'a'..=b'z'
+            | b'A'..=b'Z'
+            | b'0'..=b'9'
+            | b'-'
+            | b'_'
+            | b'.'
+            | b','
+            | b'~'
+            | b'!'
+            | b'\''
+            | b'('
+            | b')'
+            | b'*'
+            | b'/'
+            | b';'
+            | b':'
+            | b'?'
+            | b'=' => result.push(b as char),
+            b' ' => result.push('+'),
+            _ => write!(result, "%{:02X}", b).unwrap(),
+        }
+    }
+    result
+}
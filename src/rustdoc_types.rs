@@ -26,8 +26,8 @@ pub struct Crate {
     /// A collection of all items in the local crate as well as some external traits and their
     /// items that are referenced locally.
     pub index: FxHashMap<Id, Item>,
-    // /// Maps IDs to fully qualified paths and other info helpful for generating links.
-    // pub paths: FxHashMap<Id, ItemSummary>,
+    /// Maps IDs to fully qualified paths and other info helpful for generating links.
+    pub paths: FxHashMap<Id, ItemSummary>,
     // /// Maps `crate_id` of items to a crate name and html_root_url if it exists.
     // pub external_crates: FxHashMap<u32, ExternalCrate>,
     /// A single version number to be used in the future when making backwards incompatible changes
@@ -35,6 +35,8 @@ pub struct Crate {
     pub format_version: u32,
 }
 
+// Kept in sync with rustdoc, though `Crate::external_crates` is not parsed yet
+#[allow(dead_code)]
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct ExternalCrate {
     pub name: String,
@@ -458,7 +460,7 @@ pub struct Function {
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct Generics {
-    // pub params: Vec<GenericParamDef>,
+    pub params: Vec<GenericParamDef>,
     // pub where_predicates: Vec<WherePredicate>,
 }
 
@@ -509,6 +511,8 @@ pub enum GenericParamDefKind {
     },
 }
 
+// Kept in sync with rustdoc, though `Generics::where_predicates` is not parsed yet
+#[allow(dead_code, clippy::enum_variant_names)]
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum WherePredicate {
@@ -657,13 +661,13 @@ pub struct FunctionPointer {
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct FnDecl {
-    // /// List of argument names and their type.
-    // ///
-    // /// Note that not all names will be valid identifiers, as some of
-    // /// them may be patterns.
-    // pub inputs: Vec<(String, Type)>,
-    // pub output: Option<Type>,
-    // pub c_variadic: bool,
+    /// List of argument names and their type.
+    ///
+    /// Note that not all names will be valid identifiers, as some of
+    /// them may be patterns.
+    pub inputs: Vec<(String, Type)>,
+    pub output: Option<Type>,
+    pub c_variadic: bool,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
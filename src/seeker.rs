@@ -1,31 +1,95 @@
-use fst::{Automaton, IntoStreamer, Map, MapBuilder};
+use crate::markdown::{code_block_infos, is_rust_code_block, strip_markdown};
+use fst::{
+    automaton::{Levenshtein, Str},
+    map::OpBuilder,
+    Automaton,
+    IntoStreamer,
+    Map,
+    MapBuilder,
+    Streamer,
+};
 use itertools::Itertools;
+use rustc_hash::FxHashMap;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 use std::{
     cmp::{Ord, Ordering},
-    collections::BTreeSet,
+    collections::{BTreeMap, BTreeSet},
     fmt,
-    iter::FromIterator,
-    u32,
+    iter::{self, FromIterator},
+    ops::Range,
+    rc::Rc,
+    str::FromStr,
 };
+#[cfg(feature = "serde")]
+use std::io::{self, Read, Write};
 use string_cache::DefaultAtom as Atom;
+use thiserror::Error;
+use unicode_normalization::UnicodeNormalization;
 
 macro_rules! enum_number {
     ($name:ident { $($variant:ident | $display:tt | $value:tt, )* }) => {
-        #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+        #[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+        #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
         pub enum $name {
             $($variant,)*
         }
 
         impl $name {
-            fn as_str(&self) -> &'static str {
+            /// The name of the kind as in URLs, e.g. `struct` or `fn`.
+            pub fn as_str(&self) -> &'static str {
                 match self {
                     $( $name::$variant => $display, )*
                 }
             }
+
+            /// The numeric code of the kind, which never changes once assigned.
+            pub(crate) fn code(&self) -> u8 {
+                match self {
+                    $( $name::$variant => $value, )*
+                }
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = ParseDocItemKindError;
+
+            /// Parse the name of the kind as in URLs, the inverse of `as_str`.
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s {
+                    $( $display => Ok($name::$variant), )*
+                    _ => Err(ParseDocItemKindError(s.to_string())),
+                }
+            }
         }
     }
 }
 
+/// Error type for parsing a DocItemKind from an unknown name.
+///
+/// # Example
+///
+/// ```
+/// # use rustdoc_seeker::DocItemKind;
+/// assert_eq!("fn".parse(), Ok(DocItemKind::Function));
+/// assert_eq!("type".parse(), Ok(DocItemKind::Typedef));
+/// assert_eq!(DocItemKind::Method.as_str().parse(), Ok(DocItemKind::Method));
+/// assert!("function".parse::<DocItemKind>().is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("unknown kind of item: {0}")]
+pub struct ParseDocItemKindError(pub String);
+
+/// Error type for building a RustDocSeeker, see `RustDocSeekerBuilder::try_build` and
+/// `RustDocSeeker::try_merge`.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum BuildError {
+    /// There are more items than the index can address, which is `u32::MAX`. It is the number of
+    /// items to be indexed.
+    #[error("too many items to index: {0}, at most {max} are supported", max = u32::MAX)]
+    TooManyItems(usize),
+}
+
 enum_number!(DocItemKind {
     Module          | "module"          | 0,
     ExternCrate     | "externcrate"     | 1,
@@ -94,7 +158,8 @@ enum_number!(DocItemKind {
 ///     "type.vec"
 /// );
 /// ```
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TypeItem {
     pub kind: DocItemKind,
     pub name: Atom,
@@ -106,18 +171,144 @@ impl fmt::Display for TypeItem {
     }
 }
 
+/// The location of an item in the source code, see `DocItem::source_location`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SourceSpan {
+    /// The path to the source file, relative to the path rustdoc was invoked with.
+    pub filename: Atom,
+    /// Zero indexed line and column of the first character of the item.
+    pub begin: (usize, usize),
+    /// Zero indexed line and column of the last character of the item.
+    pub end: (usize, usize),
+}
+
+/// Where an associated function is defined, see `DocItem::method_origin`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum MethodOrigin {
+    /// Defined in an inherent impl like `impl<T> Vec<T>`.
+    Inherent,
+    /// Declared in a trait without a default body.
+    TraitRequired,
+    /// Declared in a trait with a default body.
+    TraitProvided,
+    /// Defined in a trait impl like `impl<T: Clone> Clone for Vec<T>`.
+    TraitImpl,
+    /// Defined in a blanket impl like `impl<T, U: From<T>> Into<U> for T`, which applies to the
+    /// type instead of being written for it.
+    BlanketImpl,
+}
+
+/// The stability of an item in the standard library, see `DocItem::stability`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Stability<'a> {
+    /// Marked as `#[stable]` since a version of Rust, e.g. `1.0.0`.
+    Stable {
+        since: &'a str,
+    },
+    /// Marked as `#[unstable]` behind a feature gate, e.g. `allocator_api`, with the number of
+    /// its tracking issue if any.
+    Unstable {
+        feature: &'a str,
+        issue: Option<&'a str>,
+    },
+    /// Marked as neither, e.g. items of crates outside the standard library.
+    Unknown,
+}
+
+/// Names of the types in the signature of a function, see `RustDocSeeker::search_signature`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub(crate) struct FnTypes {
+    pub(crate) inputs: Box<[Atom]>,
+    /// `()` for functions without a return type, `None` if the type has no name.
+    pub(crate) output: Option<Atom>,
+}
+
 /// DocItem represent a searchable item,
 /// use `Display` to get the relative URI of the item.
-#[derive(Debug, Eq)]
+///
+/// With the `serde` feature, DocItem implements `Serialize` and `Deserialize`, e.g. to send items to a browser as JSON.
+/// The serialized form follows the private fields, so it is only stable within the same version
+/// of this crate. See `RustDocSeeker::to_json` for a stable schema.
+///
+/// # Example
+///
+/// ```
+/// # use rustdoc_seeker::{DocItem, RustDoc};
+/// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+/// let items: Vec<&DocItem> = rustdoc.iter().collect();
+/// let json = serde_json::to_string(&items)?;
+/// let loaded: Vec<DocItem> = serde_json::from_str(&json)?;
+/// assert!(items.iter().copied().eq(&loaded));
+/// assert!(items.iter().zip(&loaded).all(|(a, b)| a.desc() == b.desc()));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// DocItem is `Clone`, so search results can be kept after the seeker is dropped.
+///
+/// ```
+/// # use rustdoc_seeker::{DocItem, RustDoc};
+/// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+/// let seeker = rustdoc.build();
+/// let kept: Vec<DocItem> = seeker.get_exact("dedup").cloned().collect();
+/// drop(seeker);
+/// assert!(kept.iter().any(|item| item.url() == "alloc/vec/struct.Vec.html#method.dedup"));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct DocItem {
     pub(crate) name: TypeItem,
     pub(crate) link_type: LinkType,
     pub(crate) path: Atom,
     pub(crate) desc: Atom,
+    pub(crate) must_use: Option<Atom>,
+    pub(crate) unstable_feature: Option<Atom>,
+    pub(crate) unstable_issue: Option<Atom>,
+    pub(crate) stable_since: Option<Atom>,
+    pub(crate) negative_impl: bool,
+    pub(crate) deprecated: bool,
+    pub(crate) deprecation_note: Option<Atom>,
+    pub(crate) required_assoc_const: Option<bool>,
+    pub(crate) const_value: Option<Atom>,
+    /// See `DocItem::is_public`.
+    pub(crate) public: bool,
+    /// See `RustDocSeeker::search_signature`.
+    pub(crate) fn_types: Option<FnTypes>,
+    pub(crate) generics: Option<Atom>,
+    pub(crate) signature: Option<Atom>,
+    /// See `DocItem::is_hidden`.
+    pub(crate) hidden: bool,
+    pub(crate) source_location: Option<SourceSpan>,
+    pub(crate) assoc_type_bounds: Option<Atom>,
+    /// Identifier of the impl block the item is defined in, if any.
+    pub(crate) owning_impl: Option<Atom>,
+    /// Whether the impl block the item is defined in is a blanket impl like
+    /// `impl<T> From<T> for T`.
+    pub(crate) blanket_impl: bool,
+    /// Whether the impl block the item is defined in is an auto trait impl synthesized by rustdoc.
+    pub(crate) auto_trait_impl: bool,
+    /// The trait implemented by the impl block the item is defined in.
+    pub(crate) defining_trait: Option<Atom>,
+    /// See `DocItem::method_origin`.
+    pub(crate) method_origin: Option<MethodOrigin>,
+    /// See `DocItem::is_reexport`.
+    pub(crate) reexport: bool,
+    /// Suffix of the URL fragment for associated items sharing the same name on a page,
+    /// e.g. `1` for `#method.fmt-1`. The name is never suffixed.
+    pub(crate) disambiguator: usize,
+    /// Identifier of the definition shared by all paths of the item.
+    pub(crate) def_id: Atom,
+    /// The number of distinct paths the item is reachable through.
+    pub(crate) path_count: usize,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub(crate) enum LinkType {
+/// How the URL of a DocItem is formed, see `DocItem::link_type`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum LinkType {
     /// `/std/net/index.html`
     Index,
     /// `/std/net/struct.TcpStream.html`
@@ -134,7 +325,13 @@ pub(crate) enum LinkType {
         /// `variant.Rgb`
         parent: TypeItem,
     },
-    // FIXME: how to generate fragment for impl blocks?
+    /// `/std/vec/struct.Vec.html#impl-Clone-for-Vec%3CT,+A%3E`
+    Impl {
+        /// `struct.Vec`
+        page_item: TypeItem,
+        /// `impl-Clone-for-Vec%3CT,+A%3E`
+        fragment: Atom,
+    },
 }
 
 impl DocItem {
@@ -153,187 +350,3015 @@ impl DocItem {
         &self.desc
     }
 
-    pub fn fmt_naive<W: fmt::Write>(&self, f: &mut W) -> fmt::Result {
-        write!(f, "{}::", self.path)?;
-        match &self.link_type {
-            LinkType::Index => (),
-            LinkType::Page => (),
-            LinkType::AssociateItem {
-                page_item,
-            } => write!(f, "{}::", &*page_item.name)?,
-            LinkType::SubAssociateItem {
-                page_item,
-                parent,
-            } => write!(f, "{}::{}::", &*page_item.name, &*parent.name)?,
-        }
-        write!(f, "{}", self.name)
+    /// Generic parameters of the item like `<T, A: Allocator>` for `Vec`, without defaults.
+    /// `None` if the item has no generic parameter.
+    ///
+    /// Keys of the index are still bare names, e.g. `Vec`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// let find = |url: &str| rustdoc.iter().find(|item| item.url() == url).unwrap();
+    /// let vec = find("alloc/vec/struct.Vec.html");
+    /// assert_eq!(vec.generics(), Some("<T, A: Allocator>"));
+    /// assert_eq!(format!("{}{}", vec.name(), vec.generics().unwrap()), "Vec<T, A: Allocator>");
+    /// assert_eq!(find("alloc/string/struct.String.html").generics(), None);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn generics(&self) -> Option<&str> {
+        self.generics.as_deref()
     }
 
-    pub fn fmt_url<W: fmt::Write>(&self, f: &mut W) -> fmt::Result {
-        for part in self.path.split("::") {
-            write!(f, "{}/", part)?;
-        }
-        match &self.link_type {
-            LinkType::Index => write!(f, "{}/index.html", self.name.name),
-            LinkType::Page => write!(f, "{}.html", self.name),
-            LinkType::AssociateItem {
-                page_item,
-            } => write!(f, "{}.html#{}", page_item, self.name),
-            LinkType::SubAssociateItem {
-                page_item,
-                parent,
-            } => {
-                write!(f, "{}.html#{}.{}", page_item, parent, self.name)
-            },
-        }?;
-        Ok(())
+    /// The signature of a function or method like `fn dedup(&mut self)`, rendered from the
+    /// declaration in the rustdoc JSON without visibility and where clauses. `None` for other
+    /// items.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// let find = |url: &str| rustdoc.iter().find(|item| item.url() == url).unwrap();
+    /// let dedup = find("alloc/vec/struct.Vec.html#method.dedup");
+    /// assert_eq!(dedup.signature(), Some("fn dedup(&mut self)"));
+    /// let push = find("alloc/vec/struct.Vec.html#method.push");
+    /// assert_eq!(push.signature(), Some("fn push(&mut self, value: T)"));
+    /// assert_eq!(find("alloc/vec/struct.Vec.html").signature(), None);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn signature(&self) -> Option<&str> {
+        self.signature.as_deref()
     }
 
-    fn parent_atom(&self) -> Option<&Atom> {
-        match &self.link_type {
-            LinkType::Index | LinkType::Page => None,
-            LinkType::AssociateItem {
-                page_item,
-            } => Some(&page_item.name),
-            LinkType::SubAssociateItem {
-                page_item, ..
-            } => Some(&page_item.name),
-        }
+    /// The `::` separated module path of the item, e.g. `alloc::vec` for `Vec::dedup`. It is
+    /// empty for crate root modules.
+    pub fn path(&self) -> &str {
+        &self.path
     }
 
-    fn index_key(&self) -> &[u8] {
-        self.name.name.as_bytes()
+    /// Segments of `path`, e.g. `alloc` and `vec` for `Vec::dedup`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// let dedup = rustdoc
+    ///     .iter()
+    ///     .find(|item| item.to_string() == "alloc/vec/struct.Vec.html#method.dedup")
+    ///     .unwrap();
+    /// assert_eq!(dedup.path(), "alloc::vec");
+    /// assert_eq!(dedup.path_segments().collect::<Vec<_>>(), ["alloc", "vec"]);
+    ///
+    /// let alloc = rustdoc.iter().find(|item| item.to_string() == "alloc/index.html").unwrap();
+    /// assert_eq!(alloc.path_segments().count(), 0);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn path_segments(&self) -> impl Iterator<Item=&str> {
+        // The path of a crate root module is empty
+        self.path.split("::").filter(|part| !part.is_empty())
     }
-}
 
-impl PartialEq for DocItem {
-    fn eq(&self, other: &DocItem) -> bool {
-        self.name == other.name && self.link_type == other.link_type && self.path == other.path
+    /// Segments of `path` joined by `sep` instead of `::`, e.g. `alloc.vec` for `Vec::dedup` with
+    /// `.` as `sep`. The crate name, i.e. the first segment, is left out if `strip_crate` is true.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// let find = |url: &str| rustdoc.iter().find(|item| item.url() == url).unwrap();
+    /// let btree_map = find("alloc/collections/btree_map/struct.BTreeMap.html");
+    /// assert_eq!(btree_map.path_with_sep(".", false), "alloc.collections.btree_map");
+    /// assert_eq!(btree_map.path_with_sep("/", true), "collections/btree_map");
+    /// assert_eq!(find("alloc/index.html").path_with_sep(".", true), "");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn path_with_sep(&self, sep: &str, strip_crate: bool) -> String {
+        self.path_segments().skip(strip_crate as usize).join(sep)
     }
-}
 
-impl Ord for DocItem {
-    fn cmp(&self, other: &Self) -> Ordering {
-        self.index_key()
-            .cmp(&other.index_key())
-            .then_with(|| self.path.cmp(&other.path))
-            .then_with(|| self.parent_atom().cmp(&other.parent_atom()))
+    /// The first line of the documentation string, usually a one-line summary of the item.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// let dedup = rustdoc
+    ///     .iter()
+    ///     .find(|item| item.to_string() == "alloc/vec/struct.Vec.html#method.dedup")
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     dedup.title(),
+    ///     "Removes consecutive repeated elements in the vector according to the"
+    /// );
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn title(&self) -> &str {
+        self.desc.lines().next().unwrap_or_default()
     }
-}
 
-impl PartialOrd for DocItem {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
+    /// The first paragraph of the documentation string, i.e. the text before the first blank
+    /// line, like the summary rustdoc shows in item lists. A leading `#` heading marker is
+    /// trimmed. Unlike `title`, it may span several lines, which keep their line endings.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// let dedup = rustdoc
+    ///     .iter()
+    ///     .find(|item| item.to_string() == "alloc/vec/struct.Vec.html#method.dedup")
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     dedup.summary(),
+    ///     "Removes consecutive repeated elements in the vector according to the\n\
+    ///      [`PartialEq`] trait implementation."
+    /// );
+    ///
+    /// // Blank lines are found with CRLF line endings as well.
+    /// let json = std::fs::read_to_string("doc-json/alloc.json")?.replace("\\n", "\\r\\n");
+    /// let rustdoc: RustDoc = json.parse()?;
+    /// let dedup = rustdoc
+    ///     .iter()
+    ///     .find(|item| item.to_string() == "alloc/vec/struct.Vec.html#method.dedup")
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     dedup.summary(),
+    ///     "Removes consecutive repeated elements in the vector according to the\r\n\
+    ///      [`PartialEq`] trait implementation."
+    /// );
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn summary(&self) -> &str {
+        let desc = self.desc.trim_start();
+        let len = desc
+            .split_inclusive('\n')
+            .take_while(|line| !line.trim().is_empty())
+            .map(str::len)
+            .sum();
+        desc[..len].trim_start_matches('#').trim()
     }
-}
 
-impl fmt::Display for DocItem {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        self.fmt_url(f)
+    /// The documentation string as plain text for non-HTML contexts like terminals, with code
+    /// fences, backticks of inline code and the syntax of links removed. The text of links and the
+    /// content of code blocks are kept, except lines hidden from Rust examples.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// let dedup = rustdoc
+    ///     .iter()
+    ///     .find(|item| item.to_string() == "alloc/vec/struct.Vec.html#method.dedup")
+    ///     .unwrap();
+    /// let plain = dedup.desc_plain();
+    /// assert!(plain.contains("PartialEq trait implementation."));
+    /// assert!(!plain.contains("```"));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn desc_plain(&self) -> String {
+        strip_markdown(&self.desc)
     }
-}
 
-/// RustDoc contains DocItems, which could be convert to RustDocSeeker.
-///
-/// # Example
-///
-/// ```
-/// # use rustdoc_seeker::RustDoc;
-/// # use std::fs;
-/// let data = fs::read_to_string("doc-json/std.json")?;
-/// let rustdoc: RustDoc = data.parse()?;
-/// # Ok::<(), Box<dyn std::error::Error>>(())
-/// ```
-#[derive(Debug)]
-pub struct RustDoc {
-    items: BTreeSet<DocItem>,
-}
+    /// The `summary` as plain text, see `desc_plain`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// let dedup = rustdoc
+    ///     .iter()
+    ///     .find(|item| item.to_string() == "alloc/vec/struct.Vec.html#method.dedup")
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     dedup.summary_plain(),
+    ///     "Removes consecutive repeated elements in the vector according to the\n\
+    ///      PartialEq trait implementation."
+    /// );
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn summary_plain(&self) -> String {
+        strip_markdown(self.summary())
+    }
 
-impl Extend<DocItem> for RustDoc {
-    fn extend<T: IntoIterator<Item=DocItem>>(&mut self, iter: T) {
-        self.items.extend(iter);
+    /// The number of Rust code examples in the documentation string, i.e. fenced code blocks
+    /// tested by rustdoc.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// let dedup = rustdoc
+    ///     .iter()
+    ///     .find(|item| item.to_string() == "alloc/vec/struct.Vec.html#method.dedup")
+    ///     .unwrap();
+    /// assert_eq!(dedup.example_count(), 1);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn example_count(&self) -> usize {
+        code_block_infos(&self.desc)
+            .into_iter()
+            .filter(|info| is_rust_code_block(info))
+            .count()
     }
-}
 
-impl FromIterator<DocItem> for RustDoc {
-    fn from_iter<I: IntoIterator<Item=DocItem>>(iter: I) -> Self {
-        RustDoc {
-            items: iter.into_iter().collect(),
-        }
+    /// The message of the `#[must_use]` attribute on the item, which may be empty.
+    /// `None` if the item is not marked as `#[must_use]`.
+    pub fn must_use(&self) -> Option<&str> {
+        self.must_use.as_deref()
     }
-}
 
-impl IntoIterator for RustDoc {
-    type IntoIter = <BTreeSet<DocItem> as IntoIterator>::IntoIter;
-    type Item = DocItem;
+    /// The feature gate of an `#[unstable]` item, e.g. `strict_overflow_ops`.
+    /// `None` for stable items.
+    pub fn unstable_feature(&self) -> Option<&str> {
+        self.unstable_feature.as_deref()
+    }
 
-    fn into_iter(self) -> Self::IntoIter {
-        self.items.into_iter()
+    /// The tracking issue number of an `#[unstable]` item, e.g. `118260`.
+    /// `None` for stable items and unstable items without a tracking issue.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// let push = rustdoc
+    ///     .iter()
+    ///     .find(|item| item.to_string() == "alloc/vec/struct.Vec.html#method.push_within_capacity")
+    ///     .unwrap();
+    /// assert_eq!(push.unstable_feature(), Some("vec_push_within_capacity"));
+    /// assert_eq!(push.unstable_issue(), Some("100486"));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn unstable_issue(&self) -> Option<&str> {
+        self.unstable_issue.as_deref()
     }
-}
 
-impl RustDoc {
-    pub fn new(items: BTreeSet<DocItem>) -> RustDoc {
-        RustDoc {
-            items,
+    /// Whether the item is stable, and since which version or behind which feature gate.
+    /// Associated items without their own attribute are `Stability::Unknown`, even though they
+    /// follow the stability of their parents.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::{RustDoc, Stability};
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// let find = |url: &str| rustdoc.iter().find(|item| item.to_string() == url).unwrap();
+    /// assert_eq!(
+    ///     find("alloc/vec/struct.Vec.html").stability(),
+    ///     Stability::Stable {
+    ///         since: "1.0.0"
+    ///     }
+    /// );
+    /// assert_eq!(
+    ///     find("alloc/vec/struct.Vec.html#method.push_within_capacity").stability(),
+    ///     Stability::Unstable {
+    ///         feature: "vec_push_within_capacity",
+    ///         issue: Some("100486"),
+    ///     }
+    /// );
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn stability(&self) -> Stability<'_> {
+        match (&self.unstable_feature, &self.stable_since) {
+            (Some(feature), _) => Stability::Unstable {
+                feature,
+                issue: self.unstable_issue.as_deref(),
+            },
+            (None, Some(since)) => Stability::Stable {
+                since,
+            },
+            (None, None) => Stability::Unknown,
         }
     }
 
-    pub fn iter(&self) -> impl Iterator<Item=&DocItem> {
-        self.items.iter()
+    /// Whether the item is marked as `#[deprecated]`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/core.json")?.parse()?;
+    /// let find = |url: &str| rustdoc.iter().find(|item| item.to_string() == url).unwrap();
+    /// let init = find("core/sync/atomic/constant.ATOMIC_BOOL_INIT.html");
+    /// assert!(init.is_deprecated());
+    /// assert_eq!(init.deprecation_note(), Some("the `new` function is now preferred"));
+    ///
+    /// assert!(!find("core/sync/atomic/struct.AtomicBool.html").is_deprecated());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn is_deprecated(&self) -> bool {
+        self.deprecated
     }
 
-    /// Build an index for searching
-    pub fn build(self) -> RustDocSeeker {
-        let mut builder = MapBuilder::memory();
-        let items = self.items.into_iter().collect_vec().into_boxed_slice();
+    /// The note of the `#[deprecated]` attribute on the item, e.g.
+    /// ``the `new` function is now preferred``.
+    /// `None` for items not deprecated and deprecated items without a note.
+    pub fn deprecation_note(&self) -> Option<&str> {
+        self.deprecation_note.as_deref()
+    }
 
-        assert!(items.len() as u64 <= u32::MAX as u64);
+    /// Whether the item is a negative impl block like `impl !Send for Foo`.
+    pub fn is_negative_impl(&self) -> bool {
+        self.negative_impl
+    }
 
-        {
-            let groups = items
-                .iter()
-                .enumerate()
-                .chunk_by(|(_, item)| item.index_key());
-
-            for (key, mut group) in groups.into_iter() {
-                let (start, _) = group.next().unwrap();
-                let end = group.last().map_or(start, |(i, _)| i) + 1;
-                let val = ((start as u64) << 32) + end as u64;
-                // We already sort and dedup using BTreeSet, so it always safe to unwrap.
-                builder.insert(key, val).unwrap();
+    /// The bounds of an associated type declared in a trait, e.g. `Iterator<Item = Self::Item>`
+    /// for `type IntoIter: Iterator<Item = Self::Item>`.
+    /// `None` for associated types without bounds and other items.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// let owned = rustdoc
+    ///     .iter()
+    ///     .find(|item| item.to_string() == "alloc/borrow/trait.ToOwned.html#associatedtype.Owned")
+    ///     .unwrap();
+    /// assert_eq!(owned.assoc_type_bounds(), Some("Borrow<Self>"));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn assoc_type_bounds(&self) -> Option<&str> {
+        self.assoc_type_bounds.as_deref()
+    }
+
+    /// Whether an associated const is required by a trait, i.e. declared without a value like
+    /// `const N: usize;`, rather than provided with a value like `const N: usize = 0;`.
+    /// `None` for items other than associated consts.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/core.json")?.parse()?;
+    /// let find = |url: &str| rustdoc.iter().find(|item| item.to_string() == url).unwrap();
+    /// let bits = find("core/primitive.u32.html#associatedconst.BITS");
+    /// assert_eq!(bits.is_required_assoc_const(), Some(false));
+    ///
+    /// let u32 = find("core/primitive.u32.html");
+    /// assert_eq!(u32.is_required_assoc_const(), None);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn is_required_assoc_const(&self) -> Option<bool> {
+        self.required_assoc_const
+    }
+
+    /// The location of the item in the source code, e.g. to jump to the definition from an IDE.
+    /// `None` if the rustdoc JSON document has no span for the item.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// let vec = rustdoc
+    ///     .iter()
+    ///     .find(|item| item.url() == "alloc/vec/struct.Vec.html")
+    ///     .unwrap();
+    /// let span = vec.source_location().unwrap();
+    /// assert!(span.filename.ends_with("alloc/src/vec/mod.rs"));
+    /// assert!(span.begin.0 <= span.end.0);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn source_location(&self) -> Option<&SourceSpan> {
+        self.source_location.as_ref()
+    }
+
+    /// Whether the item is marked with `#[doc(hidden)]`, or defined in a type marked with it.
+    /// Hidden items are left out of the index unless `RustDocSeekerBuilder::include_hidden`.
+    pub fn is_hidden(&self) -> bool {
+        self.hidden
+    }
+
+    /// Whether the item is visible outside its crate, i.e. not `pub(crate)` or `pub(in path)`.
+    /// Restricted items are only indexed by `RustDoc::parse_including_private`.
+    pub fn is_public(&self) -> bool {
+        self.public
+    }
+
+    /// The type an impl block is for, if the item is defined in one, which is the type whose page
+    /// the item is documented on. Same for inherent impls and trait impls, see `defining_trait`
+    /// to tell them apart.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// let find = |url: &str| rustdoc.iter().find(|item| item.to_string() == url).unwrap();
+    /// let dedup = find("alloc/vec/struct.Vec.html#method.dedup");
+    /// assert_eq!(dedup.impl_for_type().unwrap().to_string(), "struct.Vec");
+    /// assert_eq!(dedup.defining_trait(), None);
+    ///
+    /// let clone = find("alloc/vec/struct.Vec.html#method.clone");
+    /// assert_eq!(clone.impl_for_type().unwrap().to_string(), "struct.Vec");
+    /// assert_eq!(clone.defining_trait(), Some("Clone"));
+    ///
+    /// assert_eq!(find("alloc/vec/struct.Vec.html").impl_for_type(), None);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn impl_for_type(&self) -> Option<&TypeItem> {
+        match &self.link_type {
+            LinkType::AssociateItem {
+                page_item,
+            } if self.owning_impl.is_some() => Some(page_item),
+            _ => None,
+        }
+    }
+
+    /// The name of the trait implemented by the impl block the item is defined in, e.g. `Clone`.
+    /// `None` for items of inherent impls and items not defined in impl blocks.
+    pub fn defining_trait(&self) -> Option<&str> {
+        self.defining_trait.as_deref()
+    }
+
+    /// Whether the item is defined in a blanket impl like `impl<T> From<T> for T`, e.g. `into`
+    /// on the page of every type.
+    pub fn is_from_blanket_impl(&self) -> bool {
+        self.blanket_impl
+    }
+
+    /// Whether the item is an auto trait impl like `impl Send for Foo` synthesized by rustdoc, or
+    /// is defined in one. Auto traits have no associated items, so only impls are marked.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// let find = |url: &str| rustdoc.iter().find(|item| item.to_string() == url).unwrap();
+    /// let global = find("alloc/alloc/struct.Global.html#impl-Send-for-Global");
+    /// assert!(global.is_from_auto_trait_impl());
+    /// // Written by hand as `unsafe impl Send`
+    /// let thin_box = find("alloc/boxed/struct.ThinBox.html#impl-Send-for-ThinBox%3CT%3E");
+    /// assert!(!thin_box.is_from_auto_trait_impl());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn is_from_auto_trait_impl(&self) -> bool {
+        self.auto_trait_impl
+    }
+
+    /// The value of a constant or an associated constant, e.g. `9_223_372_036_854_775_807i64`
+    /// for `i64::MAX`, or the expression like `u32::BITS` if it is not evaluated by rustdoc.
+    /// `None` for other items and constants whose value is not rendered.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/core.json")?.parse()?;
+    /// let find = |url: &str| rustdoc.iter().find(|item| item.to_string() == url).unwrap();
+    /// assert_eq!(
+    ///     find("core/i64/constant.MAX.html").const_value(),
+    ///     Some("9_223_372_036_854_775_807i64")
+    /// );
+    /// assert_eq!(find("core/option/enum.Option.html").const_value(), None);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn const_value(&self) -> Option<&str> {
+        self.const_value.as_deref()
+    }
+
+    /// Where the item is defined if it is an associated function, i.e. whether it is a method of
+    /// an inherent impl, a required or provided method of a trait, or a method of a trait impl.
+    /// `None` for other items.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::{MethodOrigin, RustDoc};
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// let find = |url: &str| rustdoc.iter().find(|item| item.url() == url).unwrap();
+    /// let origin = |url: &str| find(url).method_origin();
+    /// let vec = "alloc/vec/struct.Vec.html";
+    /// assert_eq!(origin(&format!("{vec}#method.dedup")), Some(MethodOrigin::Inherent));
+    /// assert_eq!(origin(&format!("{vec}#method.clone")), Some(MethodOrigin::TraitImpl));
+    /// let to_owned = "alloc/borrow/trait.ToOwned.html";
+    /// assert_eq!(
+    ///     origin(&format!("{to_owned}#tymethod.to_owned")),
+    ///     Some(MethodOrigin::TraitRequired)
+    /// );
+    /// assert_eq!(
+    ///     origin(&format!("{to_owned}#method.clone_into")),
+    ///     Some(MethodOrigin::TraitProvided)
+    /// );
+    /// assert_eq!(origin(&format!("{vec}#method.into")), Some(MethodOrigin::BlanketImpl));
+    /// assert_eq!(origin(vec), None);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn method_origin(&self) -> Option<MethodOrigin> {
+        self.method_origin
+    }
+
+    pub fn fmt_naive<W: fmt::Write>(&self, f: &mut W) -> fmt::Result {
+        write!(f, "{}::", self.path)?;
+        match &self.link_type {
+            LinkType::Index => (),
+            LinkType::Page => (),
+            LinkType::AssociateItem {
+                page_item,
+            } => write!(f, "{}::", &*page_item.name)?,
+            LinkType::SubAssociateItem {
+                page_item,
+                parent,
+            } => write!(f, "{}::{}::", &*page_item.name, &*parent.name)?,
+            LinkType::Impl {
+                page_item, ..
+            } => write!(f, "{}::", &*page_item.name)?,
+        }
+        write!(f, "{}", self.name)
+    }
+
+    pub fn fmt_url<W: fmt::Write>(&self, f: &mut W) -> fmt::Result {
+        for part in self.path_segments() {
+            write!(f, "{}/", part)?;
+        }
+        match &self.link_type {
+            LinkType::Index => write!(f, "{}/index.html", self.name.name),
+            LinkType::Page => write!(f, "{}.html", self.name),
+            LinkType::AssociateItem {
+                page_item,
+            } => {
+                write!(f, "{}.html#{}", page_item, self.name)?;
+                if self.disambiguator > 0 {
+                    write!(f, "-{}", self.disambiguator)?;
+                }
+                Ok(())
+            },
+            LinkType::SubAssociateItem {
+                page_item,
+                parent,
+            } => {
+                write!(f, "{}.html#{}.{}", page_item, parent, self.name)
+            },
+            LinkType::Impl {
+                page_item,
+                fragment,
+            } => write!(f, "{}.html#{}", page_item, fragment),
+        }?;
+        Ok(())
+    }
+
+    /// The relative URL of the item, the same as `Display`, see `fmt_url`.
+    pub fn url(&self) -> String {
+        let mut url = String::new();
+        // Writing to String never fails.
+        self.fmt_url(&mut url).unwrap();
+        url
+    }
+
+    /// The `::` separated path of the item as written in Rust code, like
+    /// `alloc::vec::Vec::dedup` for `Vec::dedup` and `alloc` for the crate root. Unlike
+    /// `fmt_naive`, segments are not prefixed by their kinds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// let find = |url: &str| rustdoc.iter().find(|item| item.url() == url).unwrap();
+    /// let dedup = find("alloc/vec/struct.Vec.html#method.dedup");
+    /// assert_eq!(dedup.qualified_name(), "alloc::vec::Vec::dedup");
+    /// assert_eq!(find("alloc/vec/struct.Vec.html").qualified_name(), "alloc::vec::Vec");
+    /// assert_eq!(find("alloc/index.html").qualified_name(), "alloc");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn qualified_name(&self) -> String {
+        let parents = match &self.link_type {
+            LinkType::Index | LinkType::Page => [None, None],
+            LinkType::AssociateItem {
+                page_item,
             }
+            | LinkType::Impl {
+                page_item, ..
+            } => [Some(&page_item.name), None],
+            LinkType::SubAssociateItem {
+                page_item,
+                parent,
+            } => [Some(&page_item.name), Some(&parent.name)],
+        };
+        self.path_segments()
+            .chain(parents.into_iter().flatten().map(|name| &**name))
+            .chain(iter::once(self.name()))
+            .join("::")
+    }
+
+    /// The URL of the item under `base`, joined by exactly one `/` whether `base` ends with `/`
+    /// or not, see `fmt_url`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// let vec = rustdoc
+    ///     .iter()
+    ///     .find(|item| item.to_string() == "alloc/vec/struct.Vec.html")
+    ///     .unwrap();
+    /// let url = "https://doc.rust-lang.org/nightly/alloc/vec/struct.Vec.html";
+    /// assert_eq!(vec.to_url("https://doc.rust-lang.org/nightly"), url);
+    /// assert_eq!(vec.to_url("https://doc.rust-lang.org/nightly/"), url);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn to_url(&self, base: &str) -> String {
+        let mut url = String::from(base.trim_end_matches('/'));
+        url.push('/');
+        // Writing to String never fails.
+        self.fmt_url(&mut url).unwrap();
+        url
+    }
+
+    /// The absolute URL of the item on docs.rs for `version` of its crate, e.g. `latest` or
+    /// `1.0.0`, see `crate_name` and `to_url`. The relative URL already starts with the crate
+    /// name, which docs.rs expects after the version.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// let dedup = rustdoc
+    ///     .iter()
+    ///     .find(|item| item.to_string() == "alloc/vec/struct.Vec.html#method.dedup")
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     dedup.docs_rs_url("latest"),
+    ///     "https://docs.rs/alloc/latest/alloc/vec/struct.Vec.html#method.dedup"
+    /// );
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn docs_rs_url(&self, version: &str) -> String {
+        self.to_url(&format!("https://docs.rs/{}/{}", self.crate_name(), version))
+    }
+
+    /// How the URL of the item is formed, e.g. to format links differently for modules, pages
+    /// and anchors in pages.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::{LinkType, RustDoc};
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// let find = |url: &str| rustdoc.iter().find(|item| item.to_string() == url).unwrap();
+    /// assert_eq!(find("alloc/collections/index.html").link_type(), &LinkType::Index);
+    /// assert_eq!(find("alloc/vec/struct.Vec.html").link_type(), &LinkType::Page);
+    /// assert!(matches!(
+    ///     find("alloc/vec/struct.Vec.html#method.dedup").link_type(),
+    ///     LinkType::AssociateItem { page_item } if page_item.name.as_ref() == "Vec"
+    /// ));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn link_type(&self) -> &LinkType {
+        &self.link_type
+    }
+
+    /// The type owning the item on its page, e.g. `struct.Vec` for `Vec::dedup`, or `enum.Color`
+    /// for the field `r` of the variant `Color::Rgb`. It is `None` for items with their own pages.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::{DocItemKind, RustDoc, TypeItem};
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// let find = |url: &str| rustdoc.iter().find(|item| item.to_string() == url).unwrap();
+    /// assert_eq!(
+    ///     find("alloc/vec/struct.Vec.html#method.dedup").parent(),
+    ///     Some(&TypeItem {
+    ///         kind: DocItemKind::Struct,
+    ///         name: "Vec".into(),
+    ///     })
+    /// );
+    /// assert_eq!(find("alloc/vec/struct.Vec.html").parent(), None);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn parent(&self) -> Option<&TypeItem> {
+        match &self.link_type {
+            LinkType::Index | LinkType::Page => None,
+            LinkType::AssociateItem {
+                page_item,
+            } => Some(page_item),
+            LinkType::SubAssociateItem {
+                page_item, ..
+            } => Some(page_item),
+            LinkType::Impl {
+                page_item, ..
+            } => Some(page_item),
         }
+    }
 
-        let index = builder.into_map();
-        RustDocSeeker {
-            items,
-            index,
+    /// A stable identifier of the item, hashed from its kind, name, path and parent, along with
+    /// the number telling apart methods of the same name on a page and the fragment of impls,
+    /// e.g. to persist a list of recently viewed items and look them up later.
+    ///
+    /// The same item gets the same ID across runs, serialization and parsing of the same
+    /// documents, but the ID changes if the item is renamed, moved or its parent changes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// let load = || -> Result<RustDoc, Box<dyn std::error::Error>> {
+    ///     Ok(std::fs::read_to_string("doc-json/alloc.json")?.parse()?)
+    /// };
+    /// let (first, second) = (load()?, load()?);
+    /// let find = |rustdoc: &RustDoc, url: &str| {
+    ///     rustdoc.iter().find(|item| item.url() == url).unwrap().id()
+    /// };
+    /// let dedup = find(&first, "alloc/vec/struct.Vec.html#method.dedup");
+    /// assert_eq!(dedup, find(&second, "alloc/vec/struct.Vec.html#method.dedup"));
+    /// let clear = "alloc/collections/vec_deque/struct.VecDeque.html#method.clear";
+    /// assert_ne!(dedup, find(&first, clear));
+    ///
+    /// let fmt = find(&first, "alloc/string/struct.String.html#method.fmt");
+    /// assert_ne!(fmt, find(&first, "alloc/string/struct.String.html#method.fmt-1"));
+    /// let from_impls = first
+    ///     .iter()
+    ///     .filter(|item| item.url().starts_with("alloc/string/struct.String.html#impl-From"))
+    ///     .map(|item| item.id())
+    ///     .collect::<Vec<_>>();
+    /// let unique = from_impls.iter().collect::<std::collections::HashSet<_>>();
+    /// assert!(from_impls.len() > 1);
+    /// assert_eq!(unique.len(), from_impls.len());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn id(&self) -> u64 {
+        // FNV-1a, which unlike `DefaultHasher` is guaranteed to be the same everywhere.
+        fn write(hash: u64, bytes: &[u8]) -> u64 {
+            // 0xff never occurs in UTF-8, so it separates fields unambiguously.
+            bytes.iter().chain(&[0xff]).fold(hash, |hash, &byte| {
+                (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+            })
+        }
+
+        let mut hash = 0xcbf29ce484222325;
+        hash = write(hash, &[self.kind().code()]);
+        hash = write(hash, self.name().as_bytes());
+        hash = write(hash, self.path.as_bytes());
+        if let Some(parent) = self.parent() {
+            hash = write(hash, &[parent.kind.code()]);
+            hash = write(hash, parent.name.as_bytes());
+        }
+        hash = write(hash, self.disambiguator.to_string().as_bytes());
+        if let Some(fragment) = self.impl_fragment() {
+            hash = write(hash, fragment.as_bytes());
         }
+        hash
     }
-}
 
-/// RustDocSeeker contains DocItems and Index for fast searching.
-///
-/// The index is kv-map for <name, idx: u64 = (start: u32 << 32) + end: u32>
-/// where items[start..end] having the same DocItem.name.
-///
-/// # Example
-///
-/// ```
-/// # use rustdoc_seeker::RustDoc;
-/// # use std::collections::BTreeSet;
-/// # let rustdoc = RustDoc::new(BTreeSet::new());
-/// let seeker = rustdoc.build();
-/// ```
-#[derive(Debug)]
-pub struct RustDocSeeker {
-    items: Box<[DocItem]>,
-    index: Map<Vec<u8>>,
-}
+    fn parent_atom(&self) -> Option<&Atom> {
+        self.parent().map(|parent| &parent.name)
+    }
+
+    /// The name of the crate the item is defined in, which is the crate of the rustdoc JSON
+    /// document it is parsed from, e.g. `alloc` for items parsed from `alloc.json`.
+    ///
+    /// It may differ from the first segment of the path for items inlined from other crates.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// let rustdoc = RustDoc::from_paths(["doc-json/alloc.json", "doc-json/proc_macro.json"])?;
+    /// let find = |url: &str| rustdoc.iter().find(|item| item.to_string() == url).unwrap();
+    /// assert_eq!(find("alloc/vec/struct.Vec.html").crate_name(), "alloc");
+    /// assert_eq!(find("proc_macro/struct.TokenStream.html").crate_name(), "proc_macro");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn crate_name(&self) -> &str {
+        self.def_id
+            .split_once(':')
+            .map_or(&*self.def_id, |(crate_name, _)| crate_name)
+    }
+
+    /// Impls of a trait with different generic arguments on the same page only differ by this.
+    fn impl_fragment(&self) -> Option<&Atom> {
+        match &self.link_type {
+            LinkType::Impl {
+                fragment, ..
+            } => Some(fragment),
+            _ => None,
+        }
+    }
+
+    /// Whether the path of the item is a re-export, i.e. the item is not defined in the module of
+    /// the path but imported into it, or the module itself is reached through an import. See
+    /// `RustDocSeeker::canonicalize` for the shortest path among re-exports instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// let find = |url: &str| rustdoc.iter().find(|item| item.to_string() == url).unwrap();
+    /// let heap = "struct.BinaryHeap.html";
+    /// assert!(!find(&format!("alloc/collections/binary_heap/{heap}")).is_reexport());
+    /// assert!(find(&format!("alloc/collections/{heap}")).is_reexport());
+    /// assert!(find(&format!("alloc/collections/{heap}#method.new")).is_reexport());
+    /// assert!(!find(&format!("alloc/collections/binary_heap/{heap}#method.new")).is_reexport());
+    ///
+    /// // Defined in the private module `alloc::collections::btree::map`
+    /// assert!(find("alloc/collections/btree_map/struct.BTreeMap.html").is_reexport());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn is_reexport(&self) -> bool {
+        self.reexport
+    }
+
+    /// Whether two DocItems are paths of the same definition, e.g. re-exports of an item.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// let find = |url: &str| rustdoc.iter().find(|item| item.to_string() == url).unwrap();
+    /// let map = find("alloc/collections/btree_map/struct.BTreeMap.html");
+    /// assert!(map.same_definition(find("alloc/collections/struct.BTreeMap.html")));
+    /// assert!(!map.same_definition(find("alloc/vec/struct.Vec.html")));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn same_definition(&self, other: &DocItem) -> bool {
+        self.def_id == other.def_id
+    }
+
+    /// Order paths of the same definition by how canonical they are, the shorter path first,
+    /// then by `Ord` of the path.
+    fn canonical_order(&self, other: &DocItem) -> Ordering {
+        (self.path.len(), &self.path).cmp(&(other.path.len(), &other.path))
+    }
+
+    /// Compare DocItems by their names ignoring case, then by `Ord`, e.g. to sort results
+    /// alphabetically for users, where `Zip` sorts after `abs`. The index itself is always in
+    /// the byte order of `Ord`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// # let seeker = rustdoc.build();
+    /// let aut = fst::automaton::Str::new("dedup");
+    /// let dedup = seeker.search(&aut).next().unwrap();
+    /// let vec = seeker.search(&fst::automaton::Str::new("Vec")).next().unwrap();
+    /// assert!(vec < dedup);
+    /// assert!(vec.cmp_ci(dedup).is_gt());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn cmp_ci(&self, other: &DocItem) -> Ordering {
+        fn lowercase(name: &str) -> impl Iterator<Item=char> + '_ {
+            name.chars().flat_map(char::to_lowercase)
+        }
+
+        lowercase(self.name())
+            .cmp(lowercase(other.name()))
+            .then_with(|| self.cmp(other))
+    }
+
+    /// Whether two DocItems only differ by their paths, i.e. they have the same URL relative to
+    /// their modules.
+    fn same_but_path(&self, other: &DocItem) -> bool {
+        self.name == other.name
+            && self.link_type == other.link_type
+            && self.disambiguator == other.disambiguator
+    }
+
+    fn index_key(&self) -> &[u8] {
+        self.name.name.as_bytes()
+    }
+}
+
+impl PartialEq for DocItem {
+    fn eq(&self, other: &DocItem) -> bool {
+        self.name == other.name
+            && self.link_type == other.link_type
+            && self.path == other.path
+            && self.disambiguator == other.disambiguator
+    }
+}
+
+impl Ord for DocItem {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.index_key()
+            .cmp(other.index_key())
+            .then_with(|| self.path.cmp(&other.path))
+            .then_with(|| self.parent_atom().cmp(&other.parent_atom()))
+            .then_with(|| self.impl_fragment().cmp(&other.impl_fragment()))
+            .then_with(|| self.disambiguator.cmp(&other.disambiguator))
+    }
+}
+
+impl PartialOrd for DocItem {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for DocItem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_url(f)
+    }
+}
+
+/// RustDoc contains DocItems, which could be convert to RustDocSeeker.
+///
+/// # Example
+///
+/// ```
+/// # use rustdoc_seeker::RustDoc;
+/// # use std::fs;
+/// let data = fs::read_to_string("doc-json/std.json")?;
+/// let rustdoc: RustDoc = data.parse()?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct RustDoc {
+    items: BTreeSet<DocItem>,
+    /// See `RustDoc::format_versions`.
+    format_versions: BTreeSet<u32>,
+}
+
+impl Extend<DocItem> for RustDoc {
+    fn extend<T: IntoIterator<Item=DocItem>>(&mut self, iter: T) {
+        self.items.extend(iter);
+    }
+}
+
+/// Merge the items and the format versions of RustDocs, see `RustDoc::merge`.
+impl Extend<RustDoc> for RustDoc {
+    fn extend<T: IntoIterator<Item=RustDoc>>(&mut self, iter: T) {
+        for rustdoc in iter {
+            self.items.extend(rustdoc.items);
+            self.format_versions.extend(rustdoc.format_versions);
+        }
+    }
+}
+
+impl FromIterator<DocItem> for RustDoc {
+    fn from_iter<I: IntoIterator<Item=DocItem>>(iter: I) -> Self {
+        RustDoc {
+            items: iter.into_iter().collect(),
+            format_versions: BTreeSet::new(),
+        }
+    }
+}
+
+impl IntoIterator for RustDoc {
+    type IntoIter = <BTreeSet<DocItem> as IntoIterator>::IntoIter;
+    type Item = DocItem;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+impl RustDoc {
+    pub fn new(items: BTreeSet<DocItem>) -> RustDoc {
+        RustDoc {
+            items,
+            format_versions: BTreeSet::new(),
+        }
+    }
+
+    /// The `format_version`s of the rustdoc JSON documents the items are parsed from, as far as
+    /// they are tracked. More than one version means documents of different formats are combined
+    /// by `RustDoc::merge` or by extending a RustDoc with other RustDocs, whose items may be subtly
+    /// inconsistent.
+    ///
+    /// Versions are not tracked for items alone: it is empty for a RustDoc created from items,
+    /// e.g. by `RustDoc::new`, and items added by `Extend<DocItem>` do not change the versions.
+    /// Extend with whole RustDocs instead to keep track of them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// let rustdoc = RustDoc::from_paths(["doc-json/alloc.json", "doc-json/proc_macro.json"])?;
+    /// assert_eq!(rustdoc.format_versions().len(), 1);
+    ///
+    /// let mut extended = RustDoc::new(Default::default());
+    /// extended.extend([rustdoc.clone()]);
+    /// assert_eq!(extended.format_versions(), rustdoc.format_versions());
+    ///
+    /// let items: RustDoc = rustdoc.into_iter().collect();
+    /// assert!(items.format_versions().is_empty());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn format_versions(&self) -> &BTreeSet<u32> {
+        &self.format_versions
+    }
+
+    /// Merge the items and the format versions of two RustDocs, see `format_versions`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// let alloc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// let proc_macro: RustDoc = std::fs::read_to_string("doc-json/proc_macro.json")?.parse()?;
+    /// let len = alloc.len() + proc_macro.len();
+    /// let versions = alloc.format_versions().clone();
+    ///
+    /// let rustdoc = alloc.merge(proc_macro);
+    /// assert_eq!(rustdoc.len(), len);
+    /// assert_eq!(rustdoc.format_versions(), &versions);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn merge(mut self, other: RustDoc) -> RustDoc {
+        self.extend([other]);
+        self
+    }
+
+    /// Same as `RustDoc::new`, along with the format version of the document.
+    pub(crate) fn with_format_version(items: BTreeSet<DocItem>, format_version: u32) -> RustDoc {
+        RustDoc {
+            items,
+            format_versions: BTreeSet::from([format_version]),
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item=&DocItem> {
+        self.items.iter()
+    }
+
+    /// The number of items.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Whether there is no item.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Build an index for searching
+    ///
+    /// # Panics
+    ///
+    /// Panic if there are more than `u32::MAX` items, see `try_build`.
+    pub fn build(self) -> RustDocSeeker {
+        self.builder().build()
+    }
+
+    /// Same as `build`, but return `BuildError::TooManyItems` instead of panicking if there are
+    /// more than `u32::MAX` items, e.g. after merging many large crates.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// let len = rustdoc.len();
+    /// let seeker = rustdoc.try_build()?;
+    /// assert_eq!(seeker.len(), len);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn try_build(self) -> Result<RustDocSeeker, BuildError> {
+        self.builder().try_build()
+    }
+
+    /// Create a builder to build an index with options.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// # use std::collections::BTreeSet;
+    /// # let rustdoc = RustDoc::new(BTreeSet::new());
+    /// let seeker = rustdoc.builder().separate_keywords(true).build();
+    /// ```
+    pub fn builder(self) -> RustDocSeekerBuilder {
+        RustDocSeekerBuilder {
+            items: self.items,
+            separate_keywords: false,
+            max_key_len: None,
+            exclude_blanket_impls: false,
+            exclude_auto_traits: false,
+            case_insensitive: false,
+            with_descriptions: true,
+            include_hidden: false,
+        }
+    }
+}
+
+/// How the name of an item matches a query in `RustDocSeeker::search_tiered`,
+/// from the best to the worst.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum MatchTier {
+    /// The name equals the query.
+    Exact,
+    /// The name starts with the query.
+    Prefix,
+    /// The name contains the query elsewhere.
+    Substring,
+    /// The name is within an edit distance of 1 from the query.
+    Fuzzy,
+}
+
+/// An impl block of a type, returned by `RustDocSeeker::impl_blocks_for`.
+#[derive(Clone, Debug)]
+pub struct ImplBlock<'a> {
+    /// The trait implemented by the impl block, e.g. `trait.Clone`. `None` for inherent impls.
+    pub trait_: Option<TypeItem>,
+    /// The associated items defined in the impl block, one for each definition.
+    pub items: Vec<&'a DocItem>,
+}
+
+/// Statistics of the index of a RustDocSeeker, returned by `RustDocSeeker::stats`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct IndexStats {
+    /// The number of distinct keys in the index.
+    pub keys: usize,
+    /// The number of items in the index, see `RustDocSeeker::len`.
+    pub items: usize,
+    /// The size of the FST of the index in bytes.
+    pub fst_bytes: usize,
+    /// The average number of items sharing a key, `0.0` for an empty index.
+    pub avg_items_per_key: f64,
+}
+
+/// RustDocSeekerBuilder builds a RustDocSeeker with options, created by `RustDoc::builder`.
+#[derive(Debug)]
+pub struct RustDocSeekerBuilder {
+    items: BTreeSet<DocItem>,
+    separate_keywords: bool,
+    max_key_len: Option<usize>,
+    exclude_blanket_impls: bool,
+    exclude_auto_traits: bool,
+    case_insensitive: bool,
+    with_descriptions: bool,
+    include_hidden: bool,
+}
+
+impl RustDocSeekerBuilder {
+    /// Exclude keywords from `RustDocSeeker::search`, so they are only available from
+    /// `RustDocSeeker::search_keywords`, like the separate keyword section of rustdoc.
+    ///
+    /// Default is `false`.
+    pub fn separate_keywords(mut self, separate_keywords: bool) -> Self {
+        self.separate_keywords = separate_keywords;
+        self
+    }
+
+    /// Truncate keys of the index to at most `max_key_len` bytes, to keep the index small when
+    /// there are extremely long names, e.g. from generated code. The names of items are kept.
+    ///
+    /// Automatons passed to `RustDocSeeker::search` only see the truncated keys, so a query longer
+    /// than the cap should be truncated and the results post-filtered on the full name, which is
+    /// done by `search_keywords`, `complete`, `fuzzy`, `search_fuzzy_sorted`, `search_ranked`,
+    /// `crate_root` and `Autocompleter`.
+    ///
+    /// Default keeps full keys.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// let seeker = rustdoc.builder().max_key_len(4).build();
+    /// let aut = fst::automaton::Str::new("dedu");
+    /// assert_eq!(
+    ///     seeker
+    ///         .search(&aut)
+    ///         .map(|item| item.name())
+    ///         .collect::<Vec<_>>(),
+    ///     vec!["dedup", "dedup_by", "dedup_by_key"],
+    /// );
+    /// assert_eq!(seeker.crate_root("alloc").unwrap().name(), "alloc");
+    /// assert_eq!(seeker.search_fuzzy_sorted("dedup_bx", 1)[0].name(), "dedup_by");
+    /// assert_eq!(seeker.fuzzy("dedup_bx").next().unwrap().name(), "dedup_by");
+    /// assert_eq!(seeker.complete("dedup_by", 5).count(), 2);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn max_key_len(mut self, max_key_len: usize) -> Self {
+        self.max_key_len = Some(max_key_len);
+        self
+    }
+
+    /// Leave out items defined in blanket impls, see `DocItem::is_from_blanket_impl`, e.g. `into`
+    /// and `borrow` which are available on almost every type.
+    ///
+    /// Default is `false`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// let seeker = rustdoc.builder().exclude_blanket_impls(true).build();
+    /// let aut = fst::automaton::Str::new("try_into");
+    /// assert_eq!(seeker.search(&aut).count(), 0);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn exclude_blanket_impls(mut self, exclude_blanket_impls: bool) -> Self {
+        self.exclude_blanket_impls = exclude_blanket_impls;
+        self
+    }
+
+    /// Leave out auto trait impls synthesized by rustdoc like `impl Send for Global`, see
+    /// `DocItem::is_from_auto_trait_impl`.
+    ///
+    /// Default is `false`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// let url = "alloc/alloc/struct.Global.html#impl-Send-for-Global";
+    /// let aut = fst::automaton::Str::new("Send");
+    /// let seeker = rustdoc.clone().build();
+    /// assert!(seeker.search(&aut).any(|item| item.to_string() == url));
+    /// let seeker = rustdoc.builder().exclude_auto_traits(true).build();
+    /// assert!(!seeker.search(&aut).any(|item| item.to_string() == url));
+    /// assert!(seeker.search(&aut).next().is_some());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn exclude_auto_traits(mut self, exclude_auto_traits: bool) -> Self {
+        self.exclude_auto_traits = exclude_auto_traits;
+        self
+    }
+
+    /// Build another index keyed by lowercased names for `RustDocSeeker::search_ci`, which takes
+    /// extra memory for the keys and the positions of items.
+    ///
+    /// Default is `false`.
+    pub fn case_insensitive(mut self, case_insensitive: bool) -> Self {
+        self.case_insensitive = case_insensitive;
+        self
+    }
+
+    /// Keep the documentation of items, which takes most of the memory of the index. Without
+    /// descriptions, `DocItem::desc` is empty and `RustDocSeeker::search_desc` finds nothing.
+    ///
+    /// Default is `true`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// let seeker = rustdoc.builder().with_descriptions(false).build();
+    /// let aut = fst::automaton::Str::new("dedup");
+    /// assert_eq!(seeker.search(&aut).next().unwrap().desc(), "");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn with_descriptions(mut self, with_descriptions: bool) -> Self {
+        self.with_descriptions = with_descriptions;
+        self
+    }
+
+    /// Keep items marked with `#[doc(hidden)]` in the index, see `DocItem::is_hidden`. They are
+    /// left out by default, like the search of rustdoc.
+    ///
+    /// Default is `false`.
+    pub fn include_hidden(mut self, include_hidden: bool) -> Self {
+        self.include_hidden = include_hidden;
+        self
+    }
+
+    /// Build an index for searching
+    ///
+    /// # Panics
+    ///
+    /// Panic if there are more than `u32::MAX` items, see `try_build`.
+    pub fn build(self) -> RustDocSeeker {
+        self.try_build().unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Same as `build`, but return `BuildError::TooManyItems` instead of panicking if there are
+    /// more than `u32::MAX` items left after filtering.
+    pub fn try_build(self) -> Result<RustDocSeeker, BuildError> {
+        let (keywords, items): (Vec<_>, Vec<_>) = self
+            .items
+            .into_iter()
+            .filter(|item| !(self.exclude_blanket_impls && item.blanket_impl))
+            .filter(|item| !(self.exclude_auto_traits && item.auto_trait_impl))
+            .filter(|item| self.include_hidden || !item.hidden)
+            .map(|mut item| {
+                if !self.with_descriptions {
+                    item.desc = Atom::default();
+                }
+                item
+            })
+            .partition(|item| self.separate_keywords && item.kind() == DocItemKind::Keyword);
+        let items = items.into_boxed_slice();
+
+        if items.len() as u64 > u32::MAX as u64 {
+            return Err(BuildError::TooManyItems(items.len()));
+        }
+
+        // We already sort and dedup using BTreeSet, so keys are in order.
+        let index = build_index(
+            &items
+                .iter()
+                .map(|item| truncate_key(item.index_key(), self.max_key_len))
+                .collect_vec(),
+        );
+        let mut seeker = RustDocSeeker::from_parts(
+            items,
+            index,
+            keywords.into_boxed_slice(),
+            self.max_key_len,
+        );
+        if self.case_insensitive {
+            seeker.ci_index = Some(CaseInsensitiveIndex::new(&seeker.items, self.max_key_len));
+        }
+        Ok(seeker)
+    }
+}
+
+/// An index keyed by lowercased names, see `RustDocSeekerBuilder::case_insensitive`.
+///
+/// Items are not contiguous by lowercased names, so values of the index are ranges of `order`
+/// instead, which contains indices of items sorted by lowercased names.
+#[derive(Debug, Clone)]
+struct CaseInsensitiveIndex {
+    index: Map<Vec<u8>>,
+    order: Box<[u32]>,
+}
+
+impl CaseInsensitiveIndex {
+    fn new(items: &[DocItem], max_key_len: Option<usize>) -> CaseInsensitiveIndex {
+        let keys = items
+            .iter()
+            .map(|item| item.name().to_lowercase())
+            .collect_vec();
+        let mut order = (0..items.len() as u32).collect_vec();
+        // The stable sort keeps items of the same key in the order of `Ord`
+        order.sort_by(|&a, &b| keys[a as usize].cmp(&keys[b as usize]));
+        let index = build_index(
+            &order
+                .iter()
+                .map(|&i| truncate_key(keys[i as usize].as_bytes(), max_key_len))
+                .collect_vec(),
+        );
+        CaseInsensitiveIndex {
+            index,
+            order: order.into_boxed_slice(),
+        }
+    }
+}
+
+/// An index of tokens of items, like words in the documentation for
+/// `RustDocSeeker::build_desc_index`, or types in the signature for
+/// `RustDocSeeker::build_sig_index`.
+///
+/// Values of the index are positions in `postings`, which contains indices of items with the
+/// token in order.
+#[derive(Debug, Clone)]
+struct TokenIndex {
+    index: Map<Vec<u8>>,
+    postings: Box<[Box<[u32]>]>,
+}
+
+impl TokenIndex {
+    fn new<I: IntoIterator<Item=String>>(
+        items: &[DocItem],
+        item_tokens: impl Fn(&DocItem) -> I,
+    ) -> TokenIndex {
+        let mut tokens = BTreeMap::<String, Vec<u32>>::new();
+        for (i, item) in items.iter().enumerate() {
+            for token in item_tokens(item) {
+                let posting = tokens.entry(token).or_default();
+                if posting.last() != Some(&(i as u32)) {
+                    posting.push(i as u32);
+                }
+            }
+        }
+        let mut builder = MapBuilder::memory();
+        let mut postings = Vec::with_capacity(tokens.len());
+        for (token, posting) in tokens {
+            // Tokens of BTreeMap are in order, so it always safe to unwrap.
+            builder.insert(token, postings.len() as u64).unwrap();
+            postings.push(posting.into_boxed_slice());
+        }
+        TokenIndex {
+            index: builder.into_map(),
+            postings: postings.into_boxed_slice(),
+        }
+    }
+
+    /// Indices of items with `token`.
+    fn posting(&self, token: &str) -> &[u32] {
+        self.index
+            .get(token)
+            .map_or(&[], |idx| &self.postings[idx as usize])
+    }
+
+    /// Indices of items with all of `tokens`, in order. `None` if there is no token.
+    fn search(&self, tokens: impl IntoIterator<Item=String>) -> Option<Vec<u32>> {
+        let mut result: Option<Vec<u32>> = None;
+        for token in tokens {
+            let posting = self.posting(&token);
+            result = Some(match result {
+                None => posting.to_vec(),
+                Some(result) => result
+                    .into_iter()
+                    .filter(|i| posting.binary_search(i).is_ok())
+                    .collect(),
+            });
+        }
+        result
+    }
+}
+
+/// Split documentation into normalized words of alphanumeric chars, for `TokenIndex`.
+fn desc_tokens(text: &str) -> Vec<String> {
+    normalize(text)
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|token| !token.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Normalize text for matching regardless of case and composition of Unicode, i.e. lowercase it
+/// and apply NFC, e.g. `Cafe\u{301}` into `café`.
+///
+/// It is applied to both the documentation and queries of `search_desc`. Keys of the index of
+/// names are never normalized, so searches by name stay byte-exact.
+pub(crate) fn normalize(text: &str) -> String {
+    text.chars().flat_map(char::to_lowercase).nfc().collect()
+}
+
+/// Split `s` at commas not enclosed in brackets, e.g. `HashMap<K, V>, usize` into `HashMap<K, V>`
+/// and `usize`.
+fn split_top_level(s: &str) -> impl Iterator<Item=&str> {
+    let mut depth = 0;
+    s.split(move |c| {
+        match c {
+            '<' | '(' | '[' => depth += 1,
+            '>' | ')' | ']' => depth -= 1,
+            _ => (),
+        }
+        c == ',' && depth == 0
+    })
+}
+
+/// Split a query of `RustDocSeeker::search_signature` copied from a signature, e.g.
+/// `fn len(&self) -> usize` or `fn(&str) -> usize`, into the parameters and the rest after them.
+/// `None` if the query does not start with `fn`.
+fn split_fn_params(query: &str) -> Option<(&str, &str)> {
+    let rest = query.trim_start().strip_prefix("fn")?;
+    let rest = rest
+        .trim_start_matches(|c: char| c.is_alphanumeric() || c == '_' || c.is_whitespace())
+        .strip_prefix('(')?;
+    let mut depth = 0;
+    for (i, c) in rest.char_indices() {
+        match c {
+            ')' if depth == 0 => return Some((&rest[..i], &rest[i + 1..])),
+            '<' | '(' | '[' => depth += 1,
+            '>' | ')' | ']' => depth -= 1,
+            _ => (),
+        }
+    }
+    None
+}
+
+/// The lowercased name of a type in a query of `RustDocSeeker::search_signature`, like
+/// `Renderer::type_name`, e.g. `vec` for `&mut std::vec::Vec<T>`.
+fn query_type_name(ty: &str) -> Option<String> {
+    let mut ty = ty.trim();
+    // The name of a parameter copied from a signature, e.g. `s` in `s: &str`.
+    if let Some((name, rest)) = ty.split_once(':') {
+        if !rest.starts_with(':') && name.chars().all(|c| c.is_alphanumeric() || c == '_') {
+            ty = rest.trim_start();
+        }
+    }
+    // References and pointers may be nested, e.g. `&&'a mut str`.
+    loop {
+        let rest = ["&", "mut ", "*const ", "*mut ", "dyn ", "impl "]
+            .into_iter()
+            .find_map(|prefix| ty.strip_prefix(prefix))
+            .or_else(|| {
+                let lifetime = ty.strip_prefix('\'')?;
+                Some(lifetime.trim_start_matches(|c: char| c.is_alphanumeric() || c == '_'))
+            });
+        match rest {
+            Some(rest) => ty = rest.trim_start(),
+            None => break,
+        }
+    }
+    let name = if ty.starts_with('[') {
+        if ty.contains(';') {
+            "array"
+        } else {
+            "slice"
+        }
+    } else if ty.starts_with('(') {
+        if ty.trim_start_matches('(').trim_start().starts_with(')') {
+            "()"
+        } else {
+            "tuple"
+        }
+    } else if ty.starts_with("fn(") {
+        "fn"
+    } else {
+        let path = ty.split(['<', ' ']).next().unwrap_or_default();
+        path.rsplit("::").next().unwrap_or_default()
+    };
+    (!name.is_empty()).then(|| name.to_lowercase())
+}
+
+/// Build an index mapping `keys`, which are in order, to the ranges of them,
+/// see `RustDocSeeker` for the format of values.
+fn build_index(keys: &[&[u8]]) -> Map<Vec<u8>> {
+    let mut builder = MapBuilder::memory();
+    let starts = group_starts(keys);
+    for (&start, &end) in starts.iter().chain([&keys.len()]).tuple_windows() {
+        let val = ((start as u64) << 32) + end as u64;
+        // Keys are in order and grouped, so it always safe to unwrap.
+        builder.insert(keys[start], val).unwrap();
+    }
+    builder.into_map()
+}
+
+/// Indices of `keys` which differ from the previous key, i.e. the starts of groups.
+#[cfg(not(feature = "rayon"))]
+fn group_starts(keys: &[&[u8]]) -> Vec<usize> {
+    (0..keys.len())
+        .filter(|&i| i == 0 || keys[i] != keys[i - 1])
+        .collect()
+}
+
+/// Indices of `keys` which differ from the previous key, i.e. the starts of groups.
+#[cfg(feature = "rayon")]
+fn group_starts(keys: &[&[u8]]) -> Vec<usize> {
+    use rayon::prelude::*;
+
+    (0..keys.len())
+        .into_par_iter()
+        .filter(|&i| i == 0 || keys[i] != keys[i - 1])
+        .collect()
+}
+
+/// The range of items sharing a key, `idx` is a value in an index.
+fn group_range(idx: u64) -> Range<usize> {
+    let start = (idx >> 32) as usize;
+    let end = (idx & 0xffffffff) as usize;
+    start..end
+}
+
+/// RustDocSeeker contains DocItems and Index for fast searching.
+///
+/// The index is kv-map for <name, idx: u64 = (start: u32 << 32) + end: u32>
+/// where items[start..end] having the same DocItem.name.
+///
+/// The bytes of the index are stored in `D`, which is `Vec<u8>` unless loaded by `load_parts`,
+/// e.g. from a memory mapped file.
+///
+/// # Example
+///
+/// ```
+/// # use rustdoc_seeker::RustDoc;
+/// # use std::collections::BTreeSet;
+/// # let rustdoc = RustDoc::new(BTreeSet::new());
+/// let seeker = rustdoc.build();
+/// ```
+#[derive(Debug, Clone)]
+pub struct RustDocSeeker<D: AsRef<[u8]> = Vec<u8>> {
+    items: Box<[DocItem]>,
+    index: Map<D>,
+    /// Keywords excluded from the index, see `RustDocSeekerBuilder::separate_keywords`.
+    keywords: Box<[DocItem]>,
+    /// Kinds of all items, including keywords.
+    kinds: BTreeSet<DocItemKind>,
+    /// Names of the crates items are defined in.
+    crates: BTreeSet<Atom>,
+    /// Keys of the index are truncated to this many bytes, see
+    /// `RustDocSeekerBuilder::max_key_len`.
+    max_key_len: Option<usize>,
+    /// See `RustDocSeekerBuilder::case_insensitive`.
+    ci_index: Option<CaseInsensitiveIndex>,
+    /// See `RustDocSeeker::build_desc_index`.
+    desc_index: Option<TokenIndex>,
+    /// See `RustDocSeeker::build_sig_index`.
+    sig_index: Option<TokenIndex>,
+}
+
+/// Magic bytes at the start of an index saved by `RustDocSeeker::save`, ending with the version
+/// of the format.
+#[cfg(feature = "serde")]
+const SAVE_MAGIC: &[u8; 8] = b"RDSEEK\0\x01";
+
+/// The DocItems of an index saved by `RustDocSeeker::save`, followed by the bytes of the FST.
+#[cfg(feature = "serde")]
+#[derive(Serialize)]
+struct SavedItems<'a> {
+    items: &'a [DocItem],
+    keywords: &'a [DocItem],
+    max_key_len: Option<usize>,
+    case_insensitive: bool,
+}
+
+/// The DocItems of an index loaded by `RustDocSeeker::load`.
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct LoadedItems {
+    items: Box<[DocItem]>,
+    keywords: Box<[DocItem]>,
+    max_key_len: Option<usize>,
+    case_insensitive: bool,
+}
+
+/// Read the DocItems of an index saved by `RustDocSeeker::save_items`, after the magic bytes.
+#[cfg(feature = "serde")]
+fn read_saved_items<R: Read>(r: &mut R) -> io::Result<LoadedItems> {
+    let mut magic = [0; SAVE_MAGIC.len()];
+    r.read_exact(&mut magic)?;
+    if &magic != SAVE_MAGIC {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "not an index saved by rustdoc-seeker",
+        ));
+    }
+    Ok(serde_json::from_slice(&read_chunk(r)?)?)
+}
+
+/// Check that every value of a loaded `index` is a range of `items` whose keys are the key of
+/// the value, so that searching the index never goes out of bounds.
+#[cfg(feature = "serde")]
+fn validate_index<D: AsRef<[u8]>>(
+    index: &Map<D>,
+    items: &[DocItem],
+    max_key_len: Option<usize>,
+) -> io::Result<()> {
+    let mut stream = index.stream();
+    while let Some((key, idx)) = stream.next() {
+        let valid = items.get(group_range(idx)).is_some_and(|group| {
+            !group.is_empty()
+                && group
+                    .iter()
+                    .all(|item| truncate_key(item.index_key(), max_key_len) == key)
+        });
+        if !valid {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "the index does not match the saved items",
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Read a chunk of bytes prefixed by its length.
+#[cfg(feature = "serde")]
+fn read_chunk<R: Read>(r: &mut R) -> io::Result<Vec<u8>> {
+    let mut len = [0; 8];
+    r.read_exact(&mut len)?;
+    let len = u64::from_le_bytes(len);
+    let mut chunk = Vec::new();
+    r.take(len).read_to_end(&mut chunk)?;
+    if chunk.len() as u64 != len {
+        return Err(io::ErrorKind::UnexpectedEof.into());
+    }
+    Ok(chunk)
+}
+
+impl RustDocSeeker {
+    /// Merge items of two indexes into a new index, e.g. to combine indexes of crates built
+    /// separately. Items in both indexes are kept only once.
+    ///
+    /// The merged index uses `RustDocSeekerBuilder::max_key_len` of `self`, and has optional
+    /// indexes built if either has them. It takes O(n log n) time to sort items again, the same as
+    /// building from a merged RustDoc.
+    ///
+    /// # Panics
+    ///
+    /// Panic if there are more than `u32::MAX` items in the merged index, see `try_merge`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// let alloc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// let proc_macro: RustDoc = std::fs::read_to_string("doc-json/proc_macro.json")?.parse()?;
+    /// let len = alloc.len() + proc_macro.len();
+    ///
+    /// let seeker = alloc.build().merge(proc_macro.build());
+    /// assert_eq!(seeker.len(), len);
+    /// assert!(seeker.has_crate("alloc"));
+    /// assert!(seeker.has_crate("proc_macro"));
+    ///
+    /// let aut = fst::automaton::Str::new("TokenStream");
+    /// assert!(seeker.search(&aut).next().is_some());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn merge(self, other: RustDocSeeker) -> RustDocSeeker {
+        self.try_merge(other).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Same as `merge`, but return `BuildError::TooManyItems` instead of panicking if there are
+    /// more than `u32::MAX` items in the merged index.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// let alloc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// let proc_macro: RustDoc = std::fs::read_to_string("doc-json/proc_macro.json")?.parse()?;
+    /// let len = alloc.len() + proc_macro.len();
+    ///
+    /// let seeker = alloc.build().try_merge(proc_macro.build())?;
+    /// assert_eq!(seeker.len(), len);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn try_merge(self, other: RustDocSeeker) -> Result<RustDocSeeker, BuildError> {
+        let case_insensitive = self.ci_index.is_some() || other.ci_index.is_some();
+        let desc_index = self.desc_index.is_some() || other.desc_index.is_some();
+        let sig_index = self.sig_index.is_some() || other.sig_index.is_some();
+        let max_key_len = self.max_key_len;
+
+        let items: BTreeSet<_> = self
+            .items
+            .into_vec()
+            .into_iter()
+            .chain(other.items.into_vec())
+            .collect();
+        let keywords: BTreeSet<_> = self
+            .keywords
+            .into_vec()
+            .into_iter()
+            .chain(other.keywords.into_vec())
+            .collect();
+        let items: Box<[_]> = items.into_iter().collect();
+
+        if items.len() as u64 > u32::MAX as u64 {
+            return Err(BuildError::TooManyItems(items.len()));
+        }
+
+        // Items are sorted and deduped by BTreeSet, so keys are in order.
+        let index = build_index(
+            &items
+                .iter()
+                .map(|item| truncate_key(item.index_key(), max_key_len))
+                .collect_vec(),
+        );
+        let mut seeker =
+            RustDocSeeker::from_parts(items, index, keywords.into_iter().collect(), max_key_len);
+        if case_insensitive {
+            seeker.ci_index = Some(CaseInsensitiveIndex::new(&seeker.items, max_key_len));
+        }
+        if desc_index {
+            seeker.build_desc_index();
+        }
+        if sig_index {
+            seeker.build_sig_index();
+        }
+        Ok(seeker)
+    }
+
+    /// Load an index saved by `save`.
+    ///
+    /// Requires the `serde` feature, which is enabled by default.
+    ///
+    /// Fail with `io::ErrorKind::InvalidData` if the data is not saved by `save`, including
+    /// corrupted data whose index does not match the items.
+    #[cfg(feature = "serde")]
+    pub fn load<R: Read>(r: &mut R) -> io::Result<RustDocSeeker> {
+        let loaded = read_saved_items(r)?;
+        let index = read_chunk(r)?;
+        RustDocSeeker::from_loaded(loaded, index)
+    }
+}
+
+impl<D: AsRef<[u8]>> RustDocSeeker<D> {
+    fn from_parts(
+        items: Box<[DocItem]>,
+        index: Map<D>,
+        keywords: Box<[DocItem]>,
+        max_key_len: Option<usize>,
+    ) -> RustDocSeeker<D> {
+        let kinds = items
+            .iter()
+            .chain(keywords.iter())
+            .map(|item| item.kind())
+            .collect();
+        let crates = items
+            .iter()
+            .chain(keywords.iter())
+            .map(|item| Atom::from(item.crate_name()))
+            .collect();
+        RustDocSeeker {
+            items,
+            index,
+            keywords,
+            kinds,
+            crates,
+            max_key_len,
+            ci_index: None,
+            desc_index: None,
+            sig_index: None,
+        }
+    }
+
+    /// Save the index to `w`, to be loaded by `load` later without parsing rustdoc JSON and
+    /// building the index again.
+    ///
+    /// Requires the `serde` feature, which is enabled by default.
+    ///
+    /// The format is private and only readable by the same version of this crate. The index of
+    /// `RustDocSeekerBuilder::case_insensitive` is not saved, but built again by `load`. The index
+    /// of `build_desc_index` and `build_sig_index` are not saved either, call them again after
+    /// `load` if needed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::{RustDoc, RustDocSeeker};
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// # let seeker = rustdoc.build();
+    /// let mut saved = Vec::new();
+    /// seeker.save(&mut saved)?;
+    /// let loaded = RustDocSeeker::load(&mut saved.as_slice())?;
+    ///
+    /// let aut = fst::automaton::Subsequence::new("dedup");
+    /// assert_eq!(
+    ///     loaded.search(&aut).collect::<Vec<_>>(),
+    ///     seeker.search(&aut).collect::<Vec<_>>(),
+    /// );
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn save<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        self.save_items(w)?;
+        let fst = self.index.as_fst().as_bytes();
+        w.write_all(&(fst.len() as u64).to_le_bytes())?;
+        w.write_all(fst)
+    }
+
+    /// Save the items and the FST of the index separately, to be loaded by `load_parts` later.
+    /// The FST is written as is, so it can be memory mapped when loading.
+    ///
+    /// Requires the `serde` feature, which is enabled by default.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::{RustDoc, RustDocSeeker};
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// # let seeker = rustdoc.build();
+    /// let (mut items, mut fst) = (Vec::new(), Vec::new());
+    /// seeker.save_parts(&mut items, &mut fst)?;
+    /// // `fst` may be a memory mapped file instead
+    /// let loaded = RustDocSeeker::load_parts(&mut items.as_slice(), fst.as_slice())?;
+    ///
+    /// let aut = fst::automaton::Subsequence::new("dedup");
+    /// assert_eq!(
+    ///     loaded.search(&aut).collect::<Vec<_>>(),
+    ///     seeker.search(&aut).collect::<Vec<_>>(),
+    /// );
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn save_parts<W: Write, F: Write>(&self, items: &mut W, fst: &mut F) -> io::Result<()> {
+        self.save_items(items)?;
+        fst.write_all(self.index.as_fst().as_bytes())
+    }
+
+    /// Load an index saved by `save_parts`, with the FST backed by `fst`, e.g. a memory mapped
+    /// file, instead of reading it into memory.
+    ///
+    /// Requires the `serde` feature, which is enabled by default.
+    ///
+    /// Fail with `io::ErrorKind::InvalidData` if the data is not saved by `save_parts`, including
+    /// items and an FST saved from different indexes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::{Autocompleter, RustDoc, RustDocSeeker};
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// # let seeker = rustdoc.build();
+    /// let (mut items, mut fst) = (Vec::new(), Vec::new());
+    /// seeker.save_parts(&mut items, &mut fst)?;
+    /// let loaded = RustDocSeeker::load_parts(&mut items.as_slice(), fst.as_slice())?;
+    /// let mut autocompleter = Autocompleter::new(&loaded, 0);
+    /// assert_eq!(autocompleter.update("dedup_by_k")[0].name(), "dedup_by_key");
+    ///
+    /// let proc_macro: RustDoc = std::fs::read_to_string("doc-json/proc_macro.json")?.parse()?;
+    /// let (mut other_items, mut other_fst) = (Vec::new(), Vec::new());
+    /// proc_macro.build().save_parts(&mut other_items, &mut other_fst)?;
+    /// let err = RustDocSeeker::load_parts(&mut items.as_slice(), other_fst.as_slice());
+    /// assert_eq!(err.unwrap_err().kind(), std::io::ErrorKind::InvalidData);
+    /// let err = RustDocSeeker::load_parts(&mut other_items.as_slice(), fst.as_slice());
+    /// assert_eq!(err.unwrap_err().kind(), std::io::ErrorKind::InvalidData);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn load_parts<R: Read>(items: &mut R, fst: D) -> io::Result<RustDocSeeker<D>> {
+        let loaded = read_saved_items(items)?;
+        RustDocSeeker::from_loaded(loaded, fst)
+    }
+
+    #[cfg(feature = "serde")]
+    fn save_items<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        let items = serde_json::to_vec(&SavedItems {
+            items: &self.items,
+            keywords: &self.keywords,
+            max_key_len: self.max_key_len,
+            case_insensitive: self.ci_index.is_some(),
+        })?;
+        w.write_all(SAVE_MAGIC)?;
+        w.write_all(&(items.len() as u64).to_le_bytes())?;
+        w.write_all(&items)
+    }
+
+    #[cfg(feature = "serde")]
+    fn from_loaded(loaded: LoadedItems, fst: D) -> io::Result<RustDocSeeker<D>> {
+        let index =
+            Map::new(fst).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        validate_index(&index, &loaded.items, loaded.max_key_len)?;
+        let mut seeker =
+            RustDocSeeker::from_parts(loaded.items, index, loaded.keywords, loaded.max_key_len);
+        if loaded.case_insensitive {
+            seeker.ci_index = Some(CaseInsensitiveIndex::new(&seeker.items, seeker.max_key_len));
+        }
+        Ok(seeker)
+    }
+
+    /// Search with `fst::Automaton`, read `fst::automaton` for details.
+    ///
+    /// The index is traversed before the iterator is returned, collecting one value per matching
+    /// key, and the items of each key are then yielded on demand. The iterator does not borrow
+    /// `aut`, so it may be a temporary. Streaming the index lazily would need the automaton for
+    /// as long as the iterator lives, which `search_lazy` does: use it for broad queries of
+    /// which only the first results are shown, e.g. the first page of a UI, and `search` when
+    /// the automaton is built for the call or most results are consumed anyway.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// # let seeker = rustdoc.build();
+    /// let aut = regex_automata::DenseDFA::new(".*dedup.*").unwrap();
+    /// assert_eq!(
+    ///     seeker
+    ///         .search(&aut)
+    ///         .map(|item| format!("{}", item))
+    ///         .collect::<Vec<_>>(),
+    ///     vec![
+    ///         "alloc/vec/struct.Vec.html#method.dedup",
+    ///         "alloc/vec/struct.Vec.html#method.dedup_by",
+    ///         "alloc/vec/struct.Vec.html#method.dedup_by_key"
+    ///     ],
+    /// );
+    ///
+    /// let aut = fst::automaton::Levenshtein::new("dedXp", 1).unwrap();
+    /// assert_eq!(
+    ///     seeker
+    ///         .search(&aut)
+    ///         .map(|item| format!("{}", item))
+    ///         .collect::<Vec<_>>(),
+    ///     vec!["alloc/vec/struct.Vec.html#method.dedup",],
+    /// );
+    ///
+    /// let aut = fst::automaton::Subsequence::new("dedup");
+    /// assert_eq!(
+    ///     seeker
+    ///         .search(&aut)
+    ///         .map(|item| format!("{}", item))
+    ///         .collect::<Vec<_>>(),
+    ///     vec![
+    ///         "alloc/vec/struct.Vec.html#method.dedup",
+    ///         "alloc/vec/struct.Vec.html#method.dedup_by",
+    ///         "alloc/vec/struct.Vec.html#method.dedup_by_key",
+    ///     ],
+    /// );
+    ///
+    /// let result = seeker.search(&fst::automaton::Str::new("dedup"));
+    /// assert_eq!(result.count(), 1);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn search<A: Automaton>(&self, aut: &A) -> impl Iterator<Item=&DocItem> {
+        let result = self.index.search(aut).into_stream().into_values();
+        result.into_iter().flat_map(move |idx| self.group(idx))
+    }
+
+    /// Same as `search`, but lazy: each step of the iterator advances the stream of the index
+    /// only as far as the next matching key, so the first results of a broad query are available
+    /// before the whole index is traversed. The iterator borrows `aut` until it is dropped, see
+    /// `search` for when to use which.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// # let seeker = rustdoc.build();
+    /// let aut = fst::automaton::Subsequence::new("dedup");
+    /// assert!(seeker.search_lazy(&aut).eq(seeker.search(&aut)));
+    ///
+    /// // Only the first match is looked up.
+    /// let aut = fst::automaton::Subsequence::new("e");
+    /// assert!(seeker.search_lazy(&aut).next().is_some());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn search_lazy<'s, 'q, A: Automaton>(
+        &'s self,
+        aut: &'q A,
+    ) -> impl Iterator<Item=&'s DocItem> + 'q
+    where
+        's: 'q,
+    {
+        self.search_by(aut)
+    }
+
+    /// Same as `search`, taking the automaton by value so the iterator can own it.
+    fn search_by<A: Automaton>(&self, aut: A) -> impl Iterator<Item=&DocItem> {
+        let mut stream = self.index.search(aut).into_stream();
+
+        iter::from_fn(move || stream.next().map(|(_, idx)| idx))
+            .flat_map(move |idx| self.group(idx))
+    }
+
+    /// Same as `search_by`, but matching `aut` on the full names of items instead of keys
+    /// truncated by `RustDocSeekerBuilder::max_key_len`.
+    fn search_names<A: Automaton>(&self, aut: A) -> impl Iterator<Item=&DocItem> {
+        let aut = Rc::new(aut);
+        let truncated = TruncatedKeys {
+            aut: Rc::clone(&aut),
+            max_key_len: self.max_key_len.unwrap_or(usize::MAX),
+        };
+        let post_filter = self.max_key_len.is_some();
+        self.search_by(truncated)
+            .filter(move |item| !post_filter || is_full_match(&*aut, item.index_key()))
+    }
+
+    /// Same as `search`, but among items only differing by their paths, e.g. `Vec` re-exported
+    /// in several modules, only the one with the canonical path is kept.
+    ///
+    /// The canonical path is the shortest one by length in bytes, ties are broken by comparing
+    /// the paths as strings, so the result is deterministic. Associated items are compared
+    /// along with their parents, so `Vec::new` and `String::new` are both kept.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// # let seeker = rustdoc.build();
+    /// let aut = fst::automaton::Str::new("BTreeMap");
+    /// assert_eq!(seeker.search(&aut).count(), 2);
+    /// assert_eq!(
+    ///     seeker
+    ///         .search_canonical(&aut)
+    ///         .map(|item| format!("{}", item))
+    ///         .collect::<Vec<_>>(),
+    ///     vec!["alloc/collections/struct.BTreeMap.html"],
+    /// );
+    ///
+    /// let aut = fst::automaton::Str::new("new");
+    /// let parents = seeker
+    ///     .search_canonical(&aut)
+    ///     .filter_map(|item| Some(item.parent()?.name.to_string()))
+    ///     .collect::<Vec<_>>();
+    /// assert!(parents.contains(&"Vec".to_string()));
+    /// assert!(parents.contains(&"String".to_string()));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn search_canonical<A: Automaton>(&self, aut: &A) -> impl Iterator<Item=&DocItem> {
+        let result = self.index.search(aut).into_stream().into_values();
+
+        // Items differing only by paths share a key, so they are in the same group.
+        result.into_iter().flat_map(move |idx| {
+            let group = self.group(idx);
+            group.iter().filter(move |item| {
+                group
+                    .iter()
+                    .filter(|other| other.same_but_path(item))
+                    .all(|other| item.canonical_order(other).is_le())
+            })
+        })
+    }
+
+    /// Search with several `fst::Automaton`s in one pass over the index, e.g. to resolve a batch
+    /// of queries. Each item is paired with the position of the automaton matching it in `auts`.
+    ///
+    /// Items are not deduplicated: an item matched by several automata is yielded once for each
+    /// of them, in the order of the automata. Results are ordered by keys of the index, see
+    /// `search`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// # let seeker = rustdoc.build();
+    /// use fst::automaton::Str;
+    /// let auts = [Str::new("dedup"), Str::new("Vec"), Str::new("dedup")];
+    /// let results: Vec<_> = seeker
+    ///     .search_many(&auts)
+    ///     .map(|(i, item)| (i, item.url()))
+    ///     .collect();
+    /// assert!(results.contains(&(1, "alloc/vec/struct.Vec.html".to_string())));
+    /// let dedup = "alloc/vec/struct.Vec.html#method.dedup".to_string();
+    /// assert!(results.contains(&(0, dedup.clone())));
+    /// assert!(results.contains(&(2, dedup)));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn search_many<'a, A: Automaton>(
+        &'a self,
+        auts: &'a [A],
+    ) -> impl Iterator<Item=(usize, &'a DocItem)> {
+        let mut op = OpBuilder::new();
+        for aut in auts {
+            op.push(self.index.search(aut));
+        }
+        let mut stream = op.union();
+        let mut result = Vec::new();
+        while let Some((_, values)) = stream.next() {
+            let mut values = values.to_vec();
+            values.sort_by_key(|value| value.index);
+            result.extend(values.into_iter().map(|value| (value.index, value.value)));
+        }
+
+        result
+            .into_iter()
+            .flat_map(move |(i, idx)| self.group(idx).iter().map(move |item| (i, item)))
+    }
+
+    /// Count items matching `fst::Automaton`, without collecting them.
+    ///
+    /// It is the same as `search(aut).count()`, but much cheaper.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// # let seeker = rustdoc.build();
+    /// let aut = fst::automaton::Subsequence::new("dedup");
+    /// assert_eq!(seeker.count(&aut), 3);
+    /// assert_eq!(seeker.count(&aut), seeker.search(&aut).count());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn count<A: Automaton>(&self, aut: &A) -> usize {
+        let mut stream = self.index.search(aut).into_stream();
+        let mut count = 0;
+        while let Some((_, idx)) = stream.next() {
+            count += group_range(idx).len();
+        }
+        count
+    }
+
+    /// Search with `fst::Automaton`, skipping the first `offset` items and yielding at most
+    /// `limit` items, e.g. for pagination along with `count`.
+    ///
+    /// Items are in the same order as `search`, so pages are stable across calls.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// # let seeker = rustdoc.build();
+    /// let aut = fst::automaton::Subsequence::new("dedup");
+    /// let all: Vec<_> = seeker.search(&aut).collect();
+    /// let page: Vec<_> = seeker.search_page(&aut, 1, 10).collect();
+    /// assert_eq!(page, &all[1..]);
+    /// assert_eq!(seeker.search_page(&aut, 0, 1).collect::<Vec<_>>(), &all[..1]);
+    /// assert_eq!(seeker.search_page(&aut, 5, 1).count(), 0);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn search_page<A: Automaton>(
+        &self,
+        aut: &A,
+        offset: usize,
+        limit: usize,
+    ) -> impl Iterator<Item=&DocItem> {
+        let result = self.index.search(aut).into_stream().into_values();
+
+        let mut offset = offset;
+        result
+            .into_iter()
+            .filter_map(move |idx| {
+                // Skip whole groups before the offset.
+                let range = group_range(idx);
+                if offset >= range.len() {
+                    offset -= range.len();
+                    None
+                } else {
+                    let range = range.start + offset..range.end;
+                    offset = 0;
+                    Some(range)
+                }
+            })
+            .flat_map(move |range| &self.items[range])
+            .take(limit)
+    }
+
+    /// Get items named exactly `name`, e.g. both the function and the macro named `format`, by
+    /// looking up the key in the index without an automaton.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// # let seeker = rustdoc.build();
+    /// assert_eq!(
+    ///     seeker.get_exact("format").map(|item| item.url()).collect::<Vec<_>>(),
+    ///     ["alloc/macro.format.html", "alloc/fmt/fn.format.html"],
+    /// );
+    /// assert_eq!(seeker.get_exact("forma").count(), 0);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn get_exact(&self, name: &str) -> impl Iterator<Item=&DocItem> {
+        let idx = self
+            .index
+            .get(truncate_key(name.as_bytes(), self.max_key_len));
+        // Keys may be truncated, see `RustDocSeekerBuilder::max_key_len`.
+        let name = Box::<str>::from(name);
+        idx.into_iter()
+            .flat_map(move |idx| self.group(idx))
+            .filter(move |item| item.name() == &*name)
+    }
+
+    /// Look up items by a `::` qualified name like `Vec::dedup` or `alloc::vec::Vec`, as it is
+    /// written in Rust code. The last segment is looked up like `get_exact`, and the preceding
+    /// segments must match the end of the path of the item followed by its parent, so leading
+    /// modules may be left out, e.g. `vec::Vec`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// # let seeker = rustdoc.build();
+    /// let search = |query| {
+    ///     seeker
+    ///         .search_qualified(query)
+    ///         .map(|item| format!("{}", item))
+    ///         .collect::<Vec<_>>()
+    /// };
+    /// assert_eq!(search("Vec::dedup"), ["alloc/vec/struct.Vec.html#method.dedup"]);
+    /// assert_eq!(search("vec::Vec::dedup"), ["alloc/vec/struct.Vec.html#method.dedup"]);
+    /// assert_eq!(search("alloc::vec::Vec"), ["alloc/vec/struct.Vec.html"]);
+    /// assert!(search("String::dedup").is_empty());
+    /// assert_eq!(search("dedup").len(), seeker.get_exact("dedup").count());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn search_qualified<'s, 'q>(
+        &'s self,
+        query: &'q str,
+    ) -> impl Iterator<Item=&'s DocItem> + 'q
+    where
+        's: 'q,
+    {
+        let (qualifier, name) = match query.rsplit_once("::") {
+            Some((qualifier, name)) => (Some(qualifier), name),
+            None => (None, query),
+        };
+        self.get_exact(name).filter(move |item| {
+            let Some(qualifier) = qualifier else {
+                return true;
+            };
+            let mut segments = item
+                .path_segments()
+                .chain(item.parent().map(|parent| &*parent.name))
+                .collect::<Vec<_>>();
+            for expected in qualifier.rsplit("::") {
+                if segments.pop() != Some(expected) {
+                    return false;
+                }
+            }
+            true
+        })
+    }
+
+    /// Search with `fst::Automaton` on lowercased names, e.g. `Str::new("vec")` matches both `Vec`
+    /// and `vec`. The automaton should only match lowercase strings, since uppercase letters never
+    /// appear in the keys.
+    ///
+    /// Return nothing if the index is not built with `RustDocSeekerBuilder::case_insensitive`.
+    /// Results are ordered by lowercased names, then by `Ord` of DocItem.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// let seeker = rustdoc.builder().case_insensitive(true).build();
+    /// let aut = fst::automaton::Str::new("vec");
+    /// assert_eq!(
+    ///     seeker
+    ///         .search_ci(&aut)
+    ///         .map(|item| format!("{}", item))
+    ///         .collect::<Vec<_>>(),
+    ///     vec!["alloc/vec/struct.Vec.html", "alloc/macro.vec.html"],
+    /// );
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn search_ci<A: Automaton>(&self, aut: &A) -> impl Iterator<Item=&DocItem> {
+        let ci_index = self.ci_index.as_ref();
+        let result = ci_index
+            .map(|ci_index| ci_index.index.search(aut).into_stream().into_values())
+            .unwrap_or_default();
+
+        result
+            .into_iter()
+            .flat_map(move |idx| {
+                ci_index.map_or(&[][..], |ci_index| &ci_index.order[group_range(idx)])
+            })
+            .map(move |&i| &self.items[i as usize])
+    }
+
+    /// Build a full-text index of the documentation of items for `search_desc`. It is not built
+    /// by default, since it takes a lot of memory for large crates.
+    pub fn build_desc_index(&mut self) {
+        self.desc_index = Some(TokenIndex::new(&self.items, |item| desc_tokens(item.desc())));
+    }
+
+    /// Build an index of the types in the signatures of functions for `search_signature`. It is
+    /// not built by default, since it takes extra memory for every function.
+    pub fn build_sig_index(&mut self) {
+        self.sig_index = Some(TokenIndex::new(&self.items, |item| {
+            let fn_types = item.fn_types.iter();
+            fn_types
+                .flat_map(|fn_types| fn_types.inputs.iter().chain(&fn_types.output))
+                .map(|ty| ty.to_lowercase())
+                .collect::<Vec<_>>()
+        }));
+    }
+
+    /// Search items whose documentation contains all words of `query`, ignoring case, e.g.
+    /// `insertion order` matches documentation containing both `insertion` and `Order`. Words are
+    /// sequences of alphanumeric chars. Both the documentation and `query` are lowercased and
+    /// normalized to NFC, so `Misérables` matches whether `é` is precomposed or followed by a
+    /// combining mark, and so do words of other scripts like Greek.
+    ///
+    /// Return nothing if the index is not built by `build_desc_index`, or there is no word in
+    /// `query`. Results are ordered by `Ord` of DocItem.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// let mut seeker = rustdoc.build();
+    /// assert_eq!(seeker.search_desc("consecutive repeated").count(), 0);
+    ///
+    /// seeker.build_desc_index();
+    /// assert!(seeker
+    ///     .search_desc("Consecutive REPEATED")
+    ///     .any(|item| item.to_string() == "alloc/vec/struct.Vec.html#method.dedup"));
+    ///
+    /// let composed = seeker.search_desc("Mis\u{e9}rables").count();
+    /// assert!(composed > 0);
+    /// assert_eq!(seeker.search_desc("MISE\u{301}RABLES").count(), composed);
+    ///
+    /// let rustdoc: RustDoc = std::fs::read_to_string("doc-json/std.json")?.parse()?;
+    /// let mut seeker = rustdoc.build();
+    /// seeker.build_desc_index();
+    /// let composed = "\u{1f40}\u{3b4}\u{3c5}\u{3c3}\u{3c3}\u{3b5}\u{3cd}\u{3c2}";
+    /// let decomposed = "\u{3bf}\u{313}\u{3b4}\u{3c5}\u{3c3}\u{3c3}\u{3b5}\u{3c5}\u{301}\u{3c2}";
+    /// assert!(seeker.search_desc(composed).count() > 0);
+    /// assert!(seeker.search_desc(composed).eq(seeker.search_desc(decomposed)));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn search_desc(&self, query: &str) -> impl Iterator<Item=&DocItem> {
+        let result = self
+            .desc_index
+            .as_ref()
+            .and_then(|desc_index| desc_index.search(desc_tokens(query)));
+
+        result
+            .unwrap_or_default()
+            .into_iter()
+            .map(move |i| &self.items[i as usize])
+    }
+
+    /// Search functions and methods by the types of their parameters and return value, like
+    /// the search of rustdoc, e.g. `Vec<T> -> usize` matches `Vec::len`. The query is a comma
+    /// separated list of parameter types, optionally followed by `->` and the return type.
+    ///
+    /// Types are matched by their names ignoring case, references and generic arguments, e.g.
+    /// `&mut Vec<u8>` matches any `Vec`. `Self` of methods is the type they are defined for.
+    /// A function matches if each type in the query matches a distinct parameter, and the return
+    /// type matches if given. Slices, arrays, tuples and function pointers are named `[T]`,
+    /// `[T; N]`, `(A, B)` and `fn`. Results are ordered by `Ord` of DocItem.
+    ///
+    /// A signature may also be copied as the query, e.g. `fn(&'a str) -> usize` or
+    /// `fn len(&self) -> usize`, with the names of parameters and lifetimes ignored. A query
+    /// starting with a function pointer parameter is then written as `fn, ...`.
+    ///
+    /// Return nothing if the index is not built by `build_sig_index`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// let mut seeker = rustdoc.build();
+    /// assert_eq!(seeker.search_signature("&Vec<T> -> usize").count(), 0);
+    ///
+    /// seeker.build_sig_index();
+    /// let urls: Vec<_> = seeker
+    ///     .search_signature("&Vec<T> -> usize")
+    ///     .map(|item| item.url())
+    ///     .collect();
+    /// assert!(urls.contains(&"alloc/vec/struct.Vec.html#method.len".to_string()));
+    /// assert!(urls.contains(&"alloc/vec/struct.Vec.html#method.capacity".to_string()));
+    /// assert!(!urls.contains(&"alloc/vec/struct.Vec.html#method.dedup".to_string()));
+    /// assert!(seeker
+    ///     .search_signature("&&'a mut Vec<T> -> usize")
+    ///     .eq(seeker.search_signature("Vec -> usize")));
+    ///
+    /// let rustdoc: RustDoc = std::fs::read_to_string("doc-json/core.json")?.parse()?;
+    /// let mut seeker = rustdoc.build();
+    /// seeker.build_sig_index();
+    /// let urls: Vec<_> = seeker
+    ///     .search_signature("fn(&'a str) -> usize")
+    ///     .map(|item| item.url())
+    ///     .collect();
+    /// assert!(urls.contains(&"core/primitive.str.html#method.len".to_string()));
+    /// assert!(seeker
+    ///     .search_signature("fn len(s: &'a str) -> usize")
+    ///     .eq(seeker.search_signature("&str -> usize")));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn search_signature(&self, query: &str) -> impl Iterator<Item=&DocItem> {
+        let (inputs, output) = match split_fn_params(query) {
+            Some((inputs, rest)) => {
+                let output = rest.split_once("->").and_then(|(_, output)| query_type_name(output));
+                (inputs, output)
+            },
+            None => match query.split_once("->") {
+                Some((inputs, output)) => (inputs, query_type_name(output)),
+                None => (query, None),
+            },
+        };
+        let inputs = split_top_level(inputs)
+            .filter_map(query_type_name)
+            .collect::<Vec<_>>();
+        let result = self
+            .sig_index
+            .as_ref()
+            .and_then(|sig_index| sig_index.search(inputs.iter().cloned().chain(output.clone())));
+
+        result
+            .unwrap_or_default()
+            .into_iter()
+            .map(move |i| &self.items[i as usize])
+            .filter(move |item| {
+                let Some(fn_types) = &item.fn_types else {
+                    return false;
+                };
+                let output_matches = match (&output, &fn_types.output) {
+                    (None, _) => true,
+                    (Some(output), Some(ty)) => ty.to_lowercase() == *output,
+                    (Some(_), None) => false,
+                };
+                if !output_matches {
+                    return false;
+                }
+                // Each type in the query should match a distinct parameter.
+                let mut matched = vec![false; fn_types.inputs.len()];
+                inputs.iter().all(|input| {
+                    let pos = fn_types
+                        .inputs
+                        .iter()
+                        .zip(&matched)
+                        .position(|(ty, &matched)| !matched && ty.to_lowercase() == *input);
+                    pos.map(|pos| matched[pos] = true).is_some()
+                })
+            })
+    }
+
+    /// Search with `fst::Automaton` among associated items of types named `type_name`,
+    /// e.g. methods of `Vec`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// # let seeker = rustdoc.build();
+    /// use fst::{automaton::Str, Automaton};
+    ///
+    /// let aut = Str::new("dedup").starts_with();
+    /// assert_eq!(
+    ///     seeker
+    ///         .search_within_type("Vec", &aut)
+    ///         .map(|item| format!("{}", item))
+    ///         .collect::<Vec<_>>(),
+    ///     vec![
+    ///         "alloc/vec/struct.Vec.html#method.dedup",
+    ///         "alloc/vec/struct.Vec.html#method.dedup_by",
+    ///         "alloc/vec/struct.Vec.html#method.dedup_by_key",
+    ///     ],
+    /// );
+    /// assert_eq!(seeker.search_within_type("String", &aut).count(), 0);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn search_within_type<'a, A: Automaton>(
+        &'a self,
+        type_name: &'a str,
+        aut: &A,
+    ) -> impl Iterator<Item=&'a DocItem> {
+        self.search_filtered(aut, move |item| {
+            item.parent_atom()
+                .is_some_and(|parent| &**parent == type_name)
+        })
+    }
+
+    /// Search with `fst::Automaton` among items under the module `path_prefix`, e.g.
+    /// `std::collections`. The prefix matches whole segments of paths, so `std::col` does not
+    /// match items in `std::collections`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// # let seeker = rustdoc.build();
+    /// let aut = fst::automaton::Str::new("BTreeMap");
+    /// assert_eq!(
+    ///     seeker
+    ///         .search_in_path(&aut, "alloc::collections::btree_map")
+    ///         .map(|item| format!("{}", item))
+    ///         .collect::<Vec<_>>(),
+    ///     vec!["alloc/collections/btree_map/struct.BTreeMap.html"],
+    /// );
+    /// assert_eq!(seeker.search_in_path(&aut, "alloc").count(), 2);
+    /// assert_eq!(seeker.search_in_path(&aut, "alloc::coll").count(), 0);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn search_in_path<'a, A: Automaton>(
+        &'a self,
+        aut: &A,
+        path_prefix: &'a str,
+    ) -> impl Iterator<Item=&'a DocItem> {
+        self.search_filtered(aut, move |item| {
+            item.path
+                .strip_prefix(path_prefix)
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with("::"))
+        })
+    }
+
+    /// Search with `fst::Automaton` among items defined in any of `crates`, see
+    /// `DocItem::crate_name`. Unlike `search_in_path`, this follows the crate of the rustdoc JSON
+    /// document an item is parsed from, whatever its path starts with.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// let rustdoc = RustDoc::from_paths(["doc-json/core.json", "doc-json/alloc.json"])?;
+    /// let seeker = rustdoc.build();
+    /// let aut = fst::automaton::Str::new("Vec");
+    /// assert_eq!(
+    ///     seeker
+    ///         .search_in_crates(&aut, &["alloc"])
+    ///         .map(|item| format!("{}", item))
+    ///         .collect::<Vec<_>>(),
+    ///     vec!["alloc/vec/struct.Vec.html"],
+    /// );
+    /// assert_eq!(seeker.search_in_crates(&aut, &["core"]).count(), 0);
+    /// assert!(seeker
+    ///     .search_in_crates(&fst::automaton::Str::new("Option"), &["core", "std"])
+    ///     .all(|item| item.crate_name() == "core"));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn search_in_crates<'a, A: Automaton>(
+        &'a self,
+        aut: &A,
+        crates: &'a [&str],
+    ) -> impl Iterator<Item=&'a DocItem> {
+        self.search_filtered(aut, move |item| crates.contains(&item.crate_name()))
+    }
+
+    /// Search with `fst::Automaton` for items satisfying `pred`, e.g. functions of a fuzzy search.
+    ///
+    /// The index is traversed like `search`, and items are filtered as they are yielded.
+    /// `pred` is called once for each item matched by the automaton.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::{DocItemKind, RustDoc};
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// # let seeker = rustdoc.build();
+    /// let aut = fst::automaton::Levenshtein::new("vec", 1).unwrap();
+    /// assert_eq!(
+    ///     seeker
+    ///         .search_filtered(&aut, |item| item.kind() == DocItemKind::Macro)
+    ///         .map(|item| format!("{}", item))
+    ///         .collect::<Vec<_>>(),
+    ///     vec!["alloc/macro.vec.html"],
+    /// );
+    ///
+    /// let result = seeker.search_filtered(&fst::automaton::Str::new("dedup"), |_| true);
+    /// assert_eq!(result.count(), 1);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn search_filtered<'a, A: Automaton, F: Fn(&DocItem) -> bool + 'a>(
+        &'a self,
+        aut: &A,
+        pred: F,
+    ) -> impl Iterator<Item=&'a DocItem> {
+        self.search(aut).filter(move |item| pred(item))
+    }
+
+    /// Search keywords whose name starts with `query`,
+    /// whether or not they are separated from `search` by
+    /// `RustDocSeekerBuilder::separate_keywords`.
+    pub fn search_keywords<'a>(&'a self, query: &'a str) -> impl Iterator<Item=&'a DocItem> {
+        self.search_by(Str::new(self.key_prefix(query)).starts_with())
+            .filter(move |item| {
+                item.kind() == DocItemKind::Keyword && item.name().starts_with(query)
+            })
+            .chain(
+                self.keywords
+                    .iter()
+                    .filter(move |item| item.name().starts_with(query)),
+            )
+    }
+
+    /// Search items matching `query` by name, grouped into tiers of how they match, so a UI can
+    /// render a section for each tier. Tiers are in the order of `MatchTier`, empty tiers are
+    /// omitted and each item only appears in the best tier it matches.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::{MatchTier, RustDoc};
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// # let seeker = rustdoc.build();
+    /// let tiers = seeker.search_tiered("dedup_by");
+    /// assert_eq!(
+    ///     tiers
+    ///         .iter()
+    ///         .map(|(tier, items)| (*tier, items.len()))
+    ///         .collect::<Vec<_>>(),
+    ///     vec![(MatchTier::Exact, 1), (MatchTier::Prefix, 1)],
+    /// );
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn search_tiered(&self, query: &str) -> Vec<(MatchTier, Vec<&DocItem>)> {
+        let (exact, prefix): (Vec<_>, Vec<_>) = self
+            .search(&Str::new(self.key_prefix(query)).starts_with())
+            .filter(|item| item.name().starts_with(query))
+            .partition(|item| item.name() == query);
+        let substring = self
+            .items
+            .iter()
+            .filter(|item| !item.name().starts_with(query) && item.name().contains(query))
+            .collect();
+        let fuzzy = self
+            .search_fuzzy_sorted(query, 1)
+            .into_iter()
+            .filter(|item| !item.name().contains(query))
+            .collect();
+        [
+            (MatchTier::Exact, exact),
+            (MatchTier::Prefix, prefix),
+            (MatchTier::Substring, substring),
+            (MatchTier::Fuzzy, fuzzy),
+        ]
+        .into_iter()
+        .filter(|(_, items)| !items.is_empty())
+        .collect()
+    }
+
+    /// Search items whose name contains all of `words` as segments in any order, ignoring case,
+    /// e.g. `["map", "hash"]` matches `HashMap` and `hash_map`. Names are split into segments at
+    /// underscores and at case changes of CamelCase, see `name_segments`.
+    ///
+    /// This scans all items instead of the index. Every item matches if `words` is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// # let seeker = rustdoc.build();
+    /// let names = seeker
+    ///     .search_words_any_order(&["key", "by", "dedup"])
+    ///     .map(|item| item.name())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(names, vec!["dedup_by_key"]);
+    /// assert!(seeker
+    ///     .search_words_any_order(&["heap", "binary"])
+    ///     .any(|item| item.name() == "BinaryHeap"));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn search_words_any_order<'a>(
+        &'a self,
+        words: &[&str],
+    ) -> impl Iterator<Item=&'a DocItem> {
+        let words = words
+            .iter()
+            .map(|word| word.to_lowercase())
+            .collect::<BTreeSet<_>>();
+        self.items.iter().filter(move |item| {
+            let segments = name_segments(item.name())
+                .map(str::to_lowercase)
+                .collect::<BTreeSet<_>>();
+            words.is_subset(&segments)
+        })
+    }
+
+    /// Search items whose name contains the chars of `query` in order, paired with byte ranges of
+    /// the matched chars in `name`, e.g. to highlight them in an autocomplete dropdown. Adjacent
+    /// matched chars are merged into one range, and each char of `query` matches its leftmost
+    /// occurrence after the previous one.
+    ///
+    /// This scans all items instead of the index, see `fst::automaton::Subsequence` for a search
+    /// on the index without ranges. Every item matches with no range if `query` is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// # let seeker = rustdoc.build();
+    /// let (_, ranges) = seeker
+    ///     .search_highlight("ddpbk")
+    ///     .find(|(item, _)| item.name() == "dedup_by_key")
+    ///     .unwrap();
+    /// assert_eq!(ranges, vec![0..1, 2..3, 4..5, 6..7, 9..10]);
+    ///
+    /// let (_, ranges) = seeker
+    ///     .search_highlight("dedup")
+    ///     .find(|(item, _)| item.name() == "dedup")
+    ///     .unwrap();
+    /// assert_eq!(ranges, vec![0..5]);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn search_highlight<'a>(
+        &'a self,
+        query: &'a str,
+    ) -> impl Iterator<Item=(&'a DocItem, Vec<Range<usize>>)> {
+        self.items
+            .iter()
+            .filter_map(move |item| Some((item, subsequence_ranges(query, item.name())?)))
+    }
+
+    /// Get the types implementing the trait named `trait_name`, each once in the order of `Ord`
+    /// of the impls, e.g. to answer which types implement `Iterator`.
+    ///
+    /// Implementors are found from the trait impls on the pages of types, so only types
+    /// documented in the index are included. Blanket impls count as well, unless excluded by
+    /// `RustDocSeekerBuilder::exclude_blanket_impls`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::{DocItemKind, RustDoc, TypeItem};
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// # let seeker = rustdoc.build();
+    /// let implementors = seeker.implementors("Allocator").collect::<Vec<_>>();
+    /// assert!(implementors.contains(&&TypeItem {
+    ///     kind: DocItemKind::Struct,
+    ///     name: "Global".into(),
+    /// }));
+    /// assert!(seeker
+    ///     .implementors("Iterator")
+    ///     .any(|implementor| &*implementor.name == "IntoIter"));
+    /// assert_eq!(seeker.implementors("Vec").count(), 0);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn implementors<'a>(&'a self, trait_name: &str) -> impl Iterator<Item=&'a TypeItem> {
+        self.get_exact(trait_name)
+            .filter(|item| item.kind() == DocItemKind::Impl)
+            .filter_map(DocItem::parent)
+            .unique()
+    }
+
+    /// Get the associated items of `kind` on the page of types named `type_name`, e.g. the
+    /// methods of `Vec`, in the order of `Ord` of DocItem. See `impl_blocks_for` to group them
+    /// by impl blocks.
+    ///
+    /// This scans all items instead of the index. An item of a type reachable through several
+    /// paths appears once for each path.
+    ///
+    /// Items of blanket impls are included unless excluded by
+    /// `RustDocSeekerBuilder::exclude_blanket_impls`, or tell them apart by
+    /// `DocItem::method_origin`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::{DocItemKind, MethodOrigin, RustDoc};
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// let seeker = rustdoc.clone().build();
+    /// let methods = seeker
+    ///     .methods_of("Vec", DocItemKind::Method)
+    ///     .map(|item| item.name())
+    ///     .collect::<Vec<_>>();
+    /// assert!(methods.contains(&"dedup"));
+    /// assert!(methods.contains(&"push"));
+    /// assert!(!methods.contains(&"Vec"));
+    ///
+    /// let seeker = rustdoc.builder().exclude_blanket_impls(true).build();
+    /// assert!(seeker
+    ///     .methods_of("Vec", DocItemKind::Method)
+    ///     .all(|item| item.method_origin() != Some(MethodOrigin::BlanketImpl)));
+    /// assert!(seeker
+    ///     .methods_of("Vec", DocItemKind::Method)
+    ///     .all(|item| item.kind() == DocItemKind::Method));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn methods_of<'a>(
+        &'a self,
+        type_name: &'a str,
+        kind: DocItemKind,
+    ) -> impl Iterator<Item=&'a DocItem> {
+        self.items.iter().filter(move |item| {
+            item.kind() == kind
+                && item
+                    .parent_atom()
+                    .is_some_and(|parent| &**parent == type_name)
+        })
+    }
+
+    /// Get the impl blocks of types named `type_name` with the associated items defined in them,
+    /// like the implementation sections on the page of the type. Inherent impls come first,
+    /// followed by trait impls sorted by the name of the trait.
+    ///
+    /// An item reachable through several paths only appears once, represented by the DocItem
+    /// with the shortest path.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// # let seeker = rustdoc.build();
+    /// let blocks = seeker.impl_blocks_for("Vec");
+    /// assert!(blocks[0].trait_.is_none());
+    /// assert!(blocks
+    ///     .iter()
+    ///     .any(|block| block.items.iter().any(|item| item.name() == "dedup")));
+    ///
+    /// let clone = blocks
+    ///     .iter()
+    ///     .find(|block| block.trait_.as_ref().is_some_and(|trait_| &*trait_.name == "Clone"))
+    ///     .unwrap();
+    /// assert!(clone.items.iter().any(|item| item.name() == "clone"));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn impl_blocks_for(&self, type_name: &str) -> Vec<ImplBlock<'_>> {
+        let mut blocks = Vec::<ImplBlock>::new();
+        let mut block_indices = FxHashMap::<&Atom, usize>::default();
+        // The block and the position in it of each definition
+        let mut positions = FxHashMap::<&Atom, (usize, usize)>::default();
+        let items = self
+            .items
+            .iter()
+            .filter(|item| item.impl_for_type().is_some_and(|ty| &*ty.name == type_name));
+        for item in items {
+            let Some(owning_impl) = &item.owning_impl else {
+                continue;
+            };
+            let block_idx = *block_indices.entry(owning_impl).or_insert_with(|| {
+                blocks.push(ImplBlock {
+                    trait_: item.defining_trait.clone().map(|name| TypeItem {
+                        kind: DocItemKind::Trait,
+                        name,
+                    }),
+                    items: Vec::new(),
+                });
+                blocks.len() - 1
+            });
+            if let Some(&(idx, pos)) = positions.get(&item.def_id) {
+                let shortest = &mut blocks[idx].items[pos];
+                if item.canonical_order(shortest).is_lt() {
+                    *shortest = item;
+                }
+            } else {
+                let block = &mut blocks[block_idx];
+                positions.insert(&item.def_id, (block_idx, block.items.len()));
+                block.items.push(item);
+            }
+        }
+        // The stable sort keeps inherent impls in the order of their items
+        blocks.sort_by(|a, b| {
+            let trait_name = |block: &ImplBlock| block.trait_.as_ref().map(|t| t.name.clone());
+            trait_name(a).cmp(&trait_name(b))
+        });
+        blocks
+    }
+
+    /// Search with `fst::Automaton` for at most `limit` items,
+    /// along with whether there are more items beyond the limit.
+    ///
+    /// It is a safety valve for queries matching most of the index, like a subsequence of a
+    /// single letter: the search is lazy like `search_lazy`, so the index is only traversed until
+    /// one item beyond the limit is found.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// # let seeker = rustdoc.build();
+    /// let aut = fst::automaton::Subsequence::new("dedup");
+    /// let (items, truncated) = seeker.search_capped(&aut, 2);
+    /// assert_eq!(items.len(), 2);
+    /// assert!(truncated);
+    ///
+    /// let (items, truncated) = seeker.search_capped(&aut, 3);
+    /// assert_eq!(items.len(), 3);
+    /// assert!(!truncated);
+    ///
+    /// let (items, truncated) = seeker.search_capped(&fst::automaton::AlwaysMatch, 10);
+    /// assert_eq!(items.len(), 10);
+    /// assert!(truncated);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn search_capped<A: Automaton>(&self, aut: &A, limit: usize) -> (Vec<&DocItem>, bool) {
+        let mut result = self.search_lazy(aut);
+        let items = result.by_ref().take(limit).collect();
+        let truncated = result.next().is_some();
+        (items, truncated)
+    }
+
+    /// At most `limit` items whose names start with `prefix`, in the same order as `search`,
+    /// e.g. to complete a query while it is being typed. Only the prefix range of the index is
+    /// traversed, unlike a `Levenshtein` or `Subsequence` search.
+    ///
+    /// The prefix is case sensitive, see `search_ci` with `Str::new(prefix).starts_with()` for
+    /// a case insensitive completion.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// # let seeker = rustdoc.clone().build();
+    /// assert_eq!(
+    ///     seeker
+    ///         .complete("dedup", 2)
+    ///         .map(|item| format!("{}", item))
+    ///         .collect::<Vec<_>>(),
+    ///     vec![
+    ///         "alloc/vec/struct.Vec.html#method.dedup",
+    ///         "alloc/vec/struct.Vec.html#method.dedup_by",
+    ///     ],
+    /// );
+    /// assert_eq!(seeker.complete("Dedup", 2).count(), 0);
+    ///
+    /// // Names are matched in full even when keys are truncated.
+    /// let seeker = rustdoc.builder().max_key_len(4).build();
+    /// assert_eq!(seeker.complete("dedup", 5).count(), 3);
+    /// assert_eq!(seeker.complete("dedup_by", 5).count(), 2);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn complete<'s, 'p>(
+        &'s self,
+        prefix: &'p str,
+        limit: usize,
+    ) -> impl Iterator<Item=&'s DocItem> + 'p
+    where
+        's: 'p,
+    {
+        self.search_by(Str::new(self.key_prefix(prefix)).starts_with())
+            .filter(move |item| item.name().starts_with(prefix))
+            .take(limit)
+    }
+
+    /// Search with `fst::Automaton`, grouped by the kind of items, e.g. for sections of results.
+    /// Items of each kind are in the same order as `search`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::{DocItemKind, RustDoc};
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// # let seeker = rustdoc.build();
+    /// let aut = fst::automaton::Str::new("format");
+    /// let groups = seeker.search_grouped(&aut);
+    /// assert_eq!(
+    ///     groups.keys().collect::<Vec<_>>(),
+    ///     [&DocItemKind::Function, &DocItemKind::Macro],
+    /// );
+    /// assert_eq!(groups[&DocItemKind::Macro][0].url(), "alloc/macro.format.html");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn search_grouped<A: Automaton>(&self, aut: &A) -> BTreeMap<DocItemKind, Vec<&DocItem>> {
+        let mut groups = BTreeMap::<_, Vec<_>>::new();
+        for item in self.search(aut) {
+            groups.entry(item.kind()).or_default().push(item);
+        }
+        groups
+    }
 
-impl RustDocSeeker {
-    /// Search with `fst::Automaton`, read `fst::automaton` for details.
+    /// Search with `fst::Automaton`, sorted by the kind of items as displayed in URLs like `fn`
+    /// and `struct` alphabetically, then by the name, then by the full path written by
+    /// `DocItem::fmt_naive` like `alloc::vec::Vec::method.dedup`, e.g. for output that should not
+    /// depend on the layout of the index.
     ///
     /// # Example
     ///
@@ -341,49 +3366,685 @@ impl RustDocSeeker {
     /// # use rustdoc_seeker::RustDoc;
     /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
     /// # let seeker = rustdoc.build();
-    /// let aut = regex_automata::DenseDFA::new(".*dedup.*").unwrap();
+    /// let aut = fst::automaton::Str::new("format");
     /// assert_eq!(
     ///     seeker
-    ///         .search(&aut)
+    ///         .search_stable_sorted(&aut)
+    ///         .into_iter()
     ///         .map(|item| format!("{}", item))
     ///         .collect::<Vec<_>>(),
-    ///     vec![
-    ///         "alloc/vec/struct.Vec.html#method.dedup",
-    ///         "alloc/vec/struct.Vec.html#method.dedup_by",
-    ///         "alloc/vec/struct.Vec.html#method.dedup_by_key"
-    ///     ],
+    ///     vec!["alloc/fmt/fn.format.html", "alloc/macro.format.html"],
     /// );
     ///
-    /// let aut = fst::automaton::Levenshtein::new("dedXp", 1).unwrap();
+    /// use fst::Automaton;
+    /// let aut = fst::automaton::Str::new("Vec").starts_with();
+    /// let kinds = seeker
+    ///     .search_stable_sorted(&aut)
+    ///     .into_iter()
+    ///     .map(|item| item.kind().as_str())
+    ///     .collect::<Vec<_>>();
+    /// assert!(kinds.windows(2).all(|pair| pair[0] <= pair[1]));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn search_stable_sorted<A: Automaton>(&self, aut: &A) -> Vec<&DocItem> {
+        let mut result = self
+            .search(aut)
+            .map(|item| {
+                let mut path = String::new();
+                item.fmt_naive(&mut path).unwrap();
+                (item, path)
+            })
+            .collect_vec();
+        // `Ord` only breaks ties of items with colliding fragments
+        result.sort_by(|(a, a_path), (b, b_path)| {
+            a.kind()
+                .as_str()
+                .cmp(b.kind().as_str())
+                .then_with(|| a.name().cmp(b.name()))
+                .then_with(|| a_path.cmp(b_path))
+                .then_with(|| a.cmp(b))
+        });
+        result.into_iter().map(|(item, _)| item).collect()
+    }
+
+    /// Search with `fst::automaton::Levenshtein` with an edit distance picked by the length of
+    /// `query`: 0 for up to 2 chars, 1 for up to 5 chars and 2 otherwise.
+    ///
+    /// The distance is capped at 2, and lowered further if the Levenshtein automaton is too large
+    /// to build for a long query. Return nothing if even an exact automaton can not be built.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// # let seeker = rustdoc.build();
     /// assert_eq!(
     ///     seeker
-    ///         .search(&aut)
+    ///         .fuzzy("dedXp")
     ///         .map(|item| format!("{}", item))
     ///         .collect::<Vec<_>>(),
-    ///     vec!["alloc/vec/struct.Vec.html#method.dedup",],
+    ///     vec!["alloc/vec/struct.Vec.html#method.dedup"],
     /// );
+    /// assert_eq!(seeker.fuzzy(&"x".repeat(1000)).count(), 0);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn fuzzy(&self, query: &str) -> impl Iterator<Item=&DocItem> {
+        let max_distance = match query.chars().count() {
+            0..=2 => 0,
+            3..=5 => 1,
+            _ => 2,
+        };
+        (0..=max_distance)
+            .rev()
+            .find_map(|distance| Levenshtein::new(query, distance).ok())
+            .map(|aut| self.search_names(aut))
+            .into_iter()
+            .flatten()
+    }
+
+    /// Search with `fst::automaton::Levenshtein`, sorted by the edit distance from `query` to the
+    /// name of items, then by the length of the name, then by `Ord` of DocItem.
     ///
-    /// let aut = fst::automaton::Subsequence::new("dedup");
+    /// Return nothing if the Levenshtein automaton is too large to build.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// # let seeker = rustdoc.build();
+    /// let names = seeker
+    ///     .search_fuzzy_sorted("dedup", 1)
+    ///     .into_iter()
+    ///     .map(|item| item.name())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(names[0], "dedup");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn search_fuzzy_sorted(&self, query: &str, max_distance: u32) -> Vec<&DocItem> {
+        self.search_ranked(query, max_distance)
+            .into_iter()
+            .map(|(item, _)| item)
+            .collect()
+    }
+
+    /// Same as `search_fuzzy_sorted`, paired with the edit distance from `query` to the name of
+    /// each item, so exact matches come first with a distance of 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// # let seeker = rustdoc.build();
+    /// let ranked = seeker
+    ///     .search_ranked("dedup_bx", 1)
+    ///     .into_iter()
+    ///     .map(|(item, distance)| (item.name(), distance))
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(ranked, vec![("dedup_by", 1)]);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn search_ranked(&self, query: &str, max_distance: u32) -> Vec<(&DocItem, u32)> {
+        let Ok(aut) = Levenshtein::new(query, max_distance) else {
+            return Vec::new();
+        };
+        let query = query.chars().collect_vec();
+        let mut result = self
+            .search_names(aut)
+            .map(|item| {
+                let distance = edit_distances(&query, item.name())
+                    .pop()
+                    .unwrap_or_default();
+                (item, distance as u32)
+            })
+            .collect_vec();
+        // Results are already sorted by `Ord`, which is kept by the stable sort.
+        result.sort_by_key(|&(item, distance)| (distance, item.name().len()));
+        result
+    }
+
+    /// Get the root module of the crate named `crate_name`, whose `index.html` is the landing page
+    /// of the crate.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// # let seeker = rustdoc.build();
+    /// let root = seeker.crate_root("alloc").unwrap();
+    /// assert_eq!(root.name(), "alloc");
+    /// assert!(seeker.crate_root("std").is_none());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn crate_root(&self, crate_name: &str) -> Option<&DocItem> {
+        self.get_exact(crate_name)
+            .find(|item| item.kind() == DocItemKind::Module && item.path.is_empty())
+    }
+
+    /// Find the item of a relative URL, the inverse of `DocItem::fmt_url`, e.g. to look up URLs
+    /// from the history of a browser. `url` should not contain the base of the documentation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// # let seeker = rustdoc.build();
+    /// for url in [
+    ///     "alloc/index.html",
+    ///     "alloc/collections/index.html",
+    ///     "alloc/vec/struct.Vec.html",
+    ///     "alloc/vec/struct.Vec.html#method.dedup",
+    ///     "alloc/vec/struct.Vec.html#impl-Clone-for-Vec%3CT,+A%3E",
+    ///     "alloc/collections/enum.TryReserveErrorKind.html#variant.AllocError.structfield.layout",
+    /// ] {
+    ///     assert_eq!(seeker.resolve_url(url).unwrap().url(), url);
+    /// }
+    /// assert!(seeker.resolve_url("alloc/vec/struct.Vec.html#method.nothing").is_none());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn resolve_url(&self, url: &str) -> Option<&DocItem> {
+        let (page, fragment) = match url.split_once('#') {
+            Some((page, fragment)) => (page, Some(fragment)),
+            None => (url, None),
+        };
+        let name = match fragment {
+            // `impl-Clone-for-Vec%3CT,+A%3E`, where impls are named after the trait
+            Some(fragment) if fragment.starts_with("impl-") => {
+                let name = &fragment["impl-".len()..];
+                let end = name
+                    .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+                    .unwrap_or(name.len());
+                &name[..end]
+            },
+            // `method.dedup`, `method.dedup-1` or `variant.Rgb.structfield.r`
+            Some(fragment) => {
+                let name = fragment.rsplit('.').next()?;
+                match name.rsplit_once('-') {
+                    Some((name, disambiguator)) if disambiguator.parse::<usize>().is_ok() => name,
+                    _ => name,
+                }
+            },
+            None => {
+                let mut segments = page.rsplit('/');
+                match segments.next()? {
+                    "index.html" => segments.next()?,
+                    // `struct.Vec.html`
+                    page => page.strip_suffix(".html")?.split_once('.')?.1,
+                }
+            },
+        };
+
+        self.get_exact(name)
+            .chain(self.keywords.iter().filter(|item| item.name() == name))
+            .find(|item| item.url() == url)
+    }
+
+    /// Get the `n` items reachable through the most distinct paths, e.g. because of re-exports,
+    /// paired with the number of paths, sorted by the number of paths descending.
+    ///
+    /// Each item is represented by the DocItem with the shortest path.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// # let seeker = rustdoc.build();
+    /// let items = seeker.most_reexported(3);
+    /// assert_eq!(items.len(), 3);
+    /// assert!(items[0].1 > 1);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn most_reexported(&self, n: usize) -> Vec<(&DocItem, usize)> {
+        let mut definitions = FxHashMap::<&Atom, &DocItem>::default();
+        for item in self.items.iter().chain(self.keywords.iter()) {
+            definitions
+                .entry(&item.def_id)
+                .and_modify(|shortest| {
+                    if item.canonical_order(shortest).is_lt() {
+                        *shortest = item;
+                    }
+                })
+                .or_insert(item);
+        }
+        let mut result = definitions
+            .into_values()
+            .map(|item| (item, item.path_count))
+            .collect_vec();
+        result.sort_by(|(a, a_count), (b, b_count)| b_count.cmp(a_count).then_with(|| a.cmp(b)));
+        result.truncate(n);
+        result
+    }
+
+    /// Get the canonical DocItem of the definition of `item`, which is the one with the shortest
+    /// path among all re-exports, e.g. to normalize results of different searches.
+    ///
+    /// Return `item` itself if it is not in the index.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// # let seeker = rustdoc.build();
+    /// let aut = fst::automaton::Str::new("BTreeMap");
+    /// assert_eq!(seeker.search(&aut).count(), 2);
+    /// for item in seeker.search(&aut) {
+    ///     assert_eq!(
+    ///         seeker.canonicalize(item).to_string(),
+    ///         "alloc/collections/struct.BTreeMap.html"
+    ///     );
+    /// }
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn canonicalize<'a>(&'a self, item: &'a DocItem) -> &'a DocItem {
+        self.index
+            .get(truncate_key(item.index_key(), self.max_key_len))
+            .into_iter()
+            .flat_map(|idx| self.group(idx))
+            .filter(|other| other.same_definition(item))
+            .min_by(|a, b| a.canonical_order(b))
+            .unwrap_or(item)
+    }
+
+    /// The kinds of items present in the index, e.g. to offer only the available kinds for
+    /// filtering.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::{DocItemKind, RustDoc};
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// # let seeker = rustdoc.build();
+    /// let kinds = seeker.present_kinds();
+    /// assert!(kinds.contains(&DocItemKind::Struct));
+    /// assert!(!kinds.contains(&DocItemKind::Keyword));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn present_kinds(&self) -> BTreeSet<DocItemKind> {
+        self.kinds.clone()
+    }
+
+    /// Write all items, including separated keywords, as a single JSON array, e.g. for a frontend
+    /// loading the whole index at once. Items are written one by one without building the whole
+    /// document in memory.
+    ///
+    /// Each item is an object of:
+    ///
+    /// - `name`: the identifier of the item, see `DocItem::name`.
+    /// - `kind`: the type of the item as in URLs, e.g. `struct` or `fn`.
+    /// - `crate`: the crate the item is defined in, see `DocItem::crate_name`.
+    /// - `path`: the `::` separated module path of the item, empty for crate root modules.
+    /// - `url`: the relative URL of the item, see `DocItem::fmt_url`.
+    /// - `summary`: the first paragraph of the documentation, see `DocItem::summary`.
+    /// - `desc`: the whole documentation, see `DocItem::desc`.
+    /// - `parent`: the type owning the item on its page like `struct.Vec`, or `null`, see
+    ///   `DocItem::parent`.
+    /// - `must_use`: the message of `#[must_use]`, or `null`, see `DocItem::must_use`.
+    /// - `unstable_feature`: the feature gate of unstable items, or `null`.
+    /// - `deprecated`: whether the item is deprecated.
+    /// - `deprecation_note`: the note of deprecated items, or `null`.
+    /// - `signature`: the signature of functions like `fn dedup(&mut self)`, or `null`, see
+    ///   `DocItem::signature`.
+    ///
+    /// Fields may be added in later versions, but existing fields are neither removed nor
+    /// changed, so consumers should ignore unknown fields. Items are written in the order of
+    /// `iter`, followed by separated keywords, so the output is the same for the same index.
+    ///
+    /// Requires the `serde` feature, which is enabled by default.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// # let seeker = rustdoc.build();
+    /// let mut json = Vec::new();
+    /// seeker.to_json(&mut json)?;
+    /// let items: Vec<serde_json::Value> = serde_json::from_slice(&json)?;
+    /// let dedup = items
+    ///     .iter()
+    ///     .find(|item| item["url"] == "alloc/vec/struct.Vec.html#method.dedup")
+    ///     .unwrap();
+    /// assert_eq!(dedup["name"], "dedup");
+    /// assert_eq!(dedup["kind"], "method");
+    /// assert_eq!(dedup["crate"], "alloc");
+    /// assert_eq!(dedup["path"], "alloc::vec");
+    /// assert_eq!(dedup["parent"], "struct.Vec");
+    /// assert_eq!(dedup["signature"], "fn dedup(&mut self)");
     /// assert_eq!(
-    ///     seeker
-    ///         .search(&aut)
-    ///         .map(|item| format!("{}", item))
-    ///         .collect::<Vec<_>>(),
-    ///     vec![
-    ///         "alloc/vec/struct.Vec.html#method.dedup",
-    ///         "alloc/vec/struct.Vec.html#method.dedup_by",
-    ///         "alloc/vec/struct.Vec.html#method.dedup_by_key",
-    ///     ],
+    ///     dedup["summary"],
+    ///     "Removes consecutive repeated elements in the vector according to the\n\
+    ///      [`PartialEq`] trait implementation."
     /// );
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
-    pub fn search<A: Automaton>(&self, aut: &A) -> impl Iterator<Item=&DocItem> {
-        let result = self.index.search(aut).into_stream().into_values();
+    #[cfg(feature = "serde")]
+    pub fn to_json<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        w.write_all(b"[")?;
+        for (i, item) in self.items.iter().chain(self.keywords.iter()).enumerate() {
+            if i > 0 {
+                w.write_all(b",")?;
+            }
+            serde_json::to_writer(&mut *w, &JsonItem::from(item))?;
+        }
+        w.write_all(b"]")
+    }
 
-        result.into_iter().flat_map(move |idx| {
-            let start = (idx >> 32) as usize;
-            let end = (idx & 0xffffffff) as usize;
-            &self.items[start..end]
+    /// Whether any item of the crate named `crate_name` is in the index, e.g. to check that the
+    /// rustdoc JSON of the crate is loaded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// # let seeker = rustdoc.build();
+    /// assert!(seeker.has_crate("alloc"));
+    /// assert!(!seeker.has_crate("std"));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn has_crate(&self, crate_name: &str) -> bool {
+        self.crates.contains(&Atom::from(crate_name))
+    }
+
+    /// The number of items in the index, not including keywords separated by
+    /// `RustDocSeekerBuilder::separate_keywords`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// let len = rustdoc.len();
+    /// let seeker = rustdoc.build();
+    /// assert_eq!(seeker.len(), len);
+    /// assert!(!seeker.is_empty());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Whether there is no item in the index, see `len`.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Statistics of the index, e.g. to decide whether to save it with `save_parts` and load
+    /// the FST from a memory map.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// # let seeker = rustdoc.build();
+    /// let stats = seeker.stats();
+    /// assert_eq!(stats.items, seeker.len());
+    /// assert!(stats.keys < stats.items);
+    /// assert!(stats.fst_bytes > 0);
+    /// assert!(stats.avg_items_per_key > 1.0);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn stats(&self) -> IndexStats {
+        let keys = self.index.len();
+        let items = self.items.len();
+        IndexStats {
+            keys,
+            items,
+            fst_bytes: self.index.as_fst().as_bytes().len(),
+            avg_items_per_key: if keys == 0 {
+                0.0
+            } else {
+                items as f64 / keys as f64
+            },
+        }
+    }
+
+    /// Iterate all items in the index in the order of `Ord` of DocItem, not including keywords
+    /// separated by `RustDocSeekerBuilder::separate_keywords`. It yields the same items as
+    /// searching with `fst::automaton::AlwaysMatch`, without running an automaton.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// # let seeker = rustdoc.build();
+    /// assert_eq!(seeker.iter().count(), seeker.len());
+    /// assert!(seeker.iter().eq(seeker.search(&fst::automaton::AlwaysMatch)));
+    /// assert!((&seeker).into_iter().any(|item| item.name() == "Vec"));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn iter(&self) -> std::slice::Iter<'_, DocItem> {
+        self.items.iter()
+    }
+
+    /// Distinct names of items in the index in the order of keys, e.g. to feed another search
+    /// engine. Groups are walked by the keys of the index instead of every item, only the names
+    /// of groups sharing a key truncated by `RustDocSeekerBuilder::max_key_len` are compared.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rustdoc_seeker::RustDoc;
+    /// # let rustdoc: RustDoc = std::fs::read_to_string("doc-json/alloc.json")?.parse()?;
+    /// # let seeker = rustdoc.build();
+    /// let names = seeker.names().collect::<Vec<_>>();
+    /// assert_eq!(names.len(), seeker.stats().keys);
+    /// assert_eq!(names.iter().filter(|&&name| name == "new").count(), 1);
+    /// assert!(names.windows(2).all(|pair| pair[0] < pair[1]));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn names(&self) -> impl Iterator<Item=&str> {
+        let mut stream = self.index.stream();
+        let truncated = self.max_key_len.is_some();
+
+        iter::from_fn(move || stream.next().map(|(_, idx)| idx)).flat_map(move |idx| {
+            let group = self.group(idx);
+            let names = if truncated { group } else { &group[..1] };
+            names.iter().map(DocItem::name).dedup()
         })
     }
+
+    /// The longest prefix of `query` fitting in a key of the index, see
+    /// `RustDocSeekerBuilder::max_key_len`.
+    fn key_prefix<'q>(&self, query: &'q str) -> &'q str {
+        let mut prefix_len = query.len().min(self.max_key_len.unwrap_or(usize::MAX));
+        while !query.is_char_boundary(prefix_len) {
+            prefix_len -= 1;
+        }
+        &query[..prefix_len]
+    }
+
+    /// Values of groups which may have names with a prefix within `max_distance` edits of
+    /// `query`, see `group`. Keys may be truncated by `RustDocSeekerBuilder::max_key_len`, so the
+    /// names of items are to be checked by the caller.
+    pub(crate) fn fuzzy_prefix_groups(&self, query: &str, max_distance: u32) -> Vec<u64> {
+        match Levenshtein::new(query, max_distance) {
+            Ok(aut) => {
+                let truncated = TruncatedKeys {
+                    aut: Rc::new(aut.starts_with()),
+                    max_key_len: self.max_key_len.unwrap_or(usize::MAX),
+                };
+                self.index.search(truncated).into_stream().into_values()
+            },
+            // The automaton is too large, e.g. for a long query with a large distance.
+            Err(_) => self.index.stream().into_values(),
+        }
+    }
+
+    /// DocItems sharing the same name, `idx` is a value in the index.
+    pub(crate) fn group(&self, idx: u64) -> &[DocItem] {
+        &self.items[group_range(idx)]
+    }
+}
+
+impl<'a, D: AsRef<[u8]>> IntoIterator for &'a RustDocSeeker<D> {
+    type IntoIter = std::slice::Iter<'a, DocItem>;
+    type Item = &'a DocItem;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.iter()
+    }
+}
+
+/// A DocItem in the output of `RustDocSeeker::to_json`.
+#[cfg(feature = "serde")]
+#[derive(Serialize)]
+struct JsonItem<'a> {
+    name: &'a str,
+    kind: &'static str,
+    #[serde(rename = "crate")]
+    crate_name: &'a str,
+    path: &'a str,
+    url: String,
+    summary: &'a str,
+    desc: &'a str,
+    parent: Option<String>,
+    must_use: Option<&'a str>,
+    unstable_feature: Option<&'a str>,
+    deprecated: bool,
+    deprecation_note: Option<&'a str>,
+    signature: Option<&'a str>,
+}
+
+#[cfg(feature = "serde")]
+impl<'a> From<&'a DocItem> for JsonItem<'a> {
+    fn from(item: &'a DocItem) -> Self {
+        JsonItem {
+            name: item.name(),
+            kind: item.kind().as_str(),
+            crate_name: item.crate_name(),
+            path: &item.path,
+            url: item.url(),
+            summary: item.summary(),
+            desc: item.desc(),
+            parent: item.parent().map(TypeItem::to_string),
+            must_use: item.must_use(),
+            unstable_feature: item.unstable_feature(),
+            deprecated: item.is_deprecated(),
+            deprecation_note: item.deprecation_note(),
+            signature: item.signature(),
+        }
+    }
+}
+
+/// An automaton also matching keys truncated at `max_key_len` bytes which `aut` may still match
+/// after more bytes, so that items with truncated keys can be post-filtered on their full names.
+struct TruncatedKeys<A> {
+    aut: Rc<A>,
+    max_key_len: usize,
+}
+
+impl<A: Automaton> Automaton for TruncatedKeys<A> {
+    /// The state of `aut` along with the length of the key.
+    type State = (A::State, usize);
+
+    fn start(&self) -> Self::State {
+        (self.aut.start(), 0)
+    }
+
+    fn is_match(&self, (state, len): &Self::State) -> bool {
+        self.aut.is_match(state) || (*len >= self.max_key_len && self.aut.can_match(state))
+    }
+
+    fn can_match(&self, (state, _): &Self::State) -> bool {
+        self.aut.can_match(state)
+    }
+
+    fn will_always_match(&self, (state, _): &Self::State) -> bool {
+        self.aut.will_always_match(state)
+    }
+
+    fn accept(&self, (state, len): &Self::State, byte: u8) -> Self::State {
+        (self.aut.accept(state, byte), len + 1)
+    }
+}
+
+/// Whether `aut` matches the whole `key`.
+fn is_full_match<A: Automaton>(aut: &A, key: &[u8]) -> bool {
+    let mut state = aut.start();
+    for &byte in key {
+        if !aut.can_match(&state) {
+            return false;
+        }
+        state = aut.accept(&state, byte);
+    }
+    aut.is_match(&state)
+}
+
+/// Truncate `key` to at most `max_key_len` bytes, if any.
+fn truncate_key(key: &[u8], max_key_len: Option<usize>) -> &[u8] {
+    match max_key_len {
+        Some(max_key_len) => &key[..key.len().min(max_key_len)],
+        None => key,
+    }
+}
+
+/// Split an identifier into segments at underscores and at case changes of CamelCase,
+/// e.g. `dedup_by_key` into `dedup`, `by` and `key`, and `BTreeMap` into `B`, `Tree` and `Map`.
+fn name_segments(name: &str) -> impl Iterator<Item=&str> {
+    name.split('_').filter(|part| !part.is_empty()).flat_map(|part| {
+        let chars = part.char_indices().collect_vec();
+        let mut starts = vec![0];
+        for (j, &(i, c)) in chars.iter().enumerate().skip(1) {
+            let prev = chars[j - 1].1;
+            let next_is_lower = chars.get(j + 1).is_some_and(|(_, next)| next.is_lowercase());
+            // A new segment starts at an uppercase letter after a lowercase letter or a digit,
+            // and at the last uppercase letter of an acronym followed by a lowercase letter.
+            if c.is_uppercase()
+                && (prev.is_lowercase()
+                    || prev.is_ascii_digit()
+                    || (prev.is_uppercase() && next_is_lower))
+            {
+                starts.push(i);
+            }
+        }
+        starts.push(part.len());
+        starts
+            .into_iter()
+            .tuple_windows()
+            .map(move |(start, end)| &part[start..end])
+    })
+}
+
+/// Match the chars of `query` as a subsequence of `name`, taking the leftmost occurrence of each
+/// char, and return byte ranges of the matched chars in `name`, with adjacent chars merged into
+/// one range. `None` if `name` does not contain `query` as a subsequence.
+fn subsequence_ranges(query: &str, name: &str) -> Option<Vec<Range<usize>>> {
+    let mut ranges = Vec::<Range<usize>>::new();
+    let mut chars = name.char_indices();
+    for q in query.chars() {
+        let (start, c) = chars.find(|&(_, c)| c == q)?;
+        let end = start + c.len_utf8();
+        match ranges.last_mut() {
+            Some(last) if last.end == start => last.end = end,
+            _ => ranges.push(start..end),
+        }
+    }
+    Some(ranges)
+}
+
+/// Edit distances between `query` and every prefix of `name`,
+/// i.e. the `j`th distance is between `query` and the first `j` chars of `name`.
+pub(crate) fn edit_distances(query: &[char], name: &str) -> Vec<usize> {
+    // row[j] is the edit distance between query[..i] and name[..j]
+    let name = name.chars().collect_vec();
+    let mut row = (0..=name.len()).collect_vec();
+    for (i, &q) in query.iter().enumerate() {
+        let mut prev = row[0];
+        row[0] = i + 1;
+        for (j, &n) in name.iter().enumerate() {
+            let cost = if q == n { prev } else { prev + 1 };
+            prev = row[j + 1];
+            row[j + 1] = cost.min(row[j] + 1).min(prev + 1);
+        }
+    }
+    row
 }